@@ -0,0 +1,129 @@
+//! Derive macros for
+//! [leptos_form_tool](https://crates.io/crates/leptos_form_tool).
+//!
+//! These are re-exported by `leptos_form_tool` with the `derive` feature,
+//! and should be used from there.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, LitStr};
+
+/// Derives `FormOptions` for an enum with only unit variants.
+///
+/// Each variant becomes an option. By default, the variant's name is used
+/// for both the display text and the value. These can be changed with the
+/// `#[form(label = "...")]` and `#[form(value = "...")]` attributes.
+///
+/// This also derives `Display` and `FromStr` using the value strings, so
+/// the enum can be used with the other parse functions too.
+///
+/// ```ignore
+/// #[derive(Clone, PartialEq, FormOptions)]
+/// enum Size {
+///     #[form(label = "Small (8oz)")]
+///     Small,
+///     #[form(label = "Large (16oz)", value = "lg")]
+///     Large,
+/// }
+/// ```
+#[proc_macro_derive(FormOptions, attributes(form))]
+pub fn derive_form_options(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match form_options(input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// A variant of the enum, with its display text and value.
+struct OptionVariant {
+    ident: syn::Ident,
+    label: String,
+    value: String,
+}
+
+fn form_options(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "FormOptions can only be derived for enums",
+        ));
+    };
+
+    let mut variants = Vec::new();
+    for variant in data.variants.iter() {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "FormOptions can only be derived for enums with unit variants",
+            ));
+        }
+        let mut label = None;
+        let mut value = None;
+        for attr in variant.attrs.iter().filter(|a| a.path().is_ident("form")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("label") {
+                    label = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident("value") {
+                    value = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `label` or `value`"))
+                }
+            })?;
+        }
+        let name = variant.ident.to_string();
+        variants.push(OptionVariant {
+            ident: variant.ident.clone(),
+            label: label.unwrap_or_else(|| name.clone()),
+            value: value.unwrap_or(name),
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let idents = variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+    let labels = variants.iter().map(|v| &v.label);
+    let values = variants.iter().map(|v| &v.value).collect::<Vec<_>>();
+    let error = format!("'{{}}' is not a valid {}", ident);
+
+    Ok(quote! {
+        impl #impl_generics ::leptos_form_tool::controls::FormOptions for #ident #ty_generics #where_clause {
+            fn variants() -> ::std::vec::Vec<Self> {
+                ::std::vec![#(Self::#idents),*]
+            }
+
+            fn display(&self) -> ::std::string::String {
+                match self {
+                    #(Self::#idents => ::std::string::String::from(#labels),)*
+                }
+            }
+
+            fn value(&self) -> ::std::string::String {
+                match self {
+                    #(Self::#idents => ::std::string::String::from(#values),)*
+                }
+            }
+        }
+
+        impl #impl_generics ::std::fmt::Display for #ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                match self {
+                    #(Self::#idents => f.write_str(#values),)*
+                }
+            }
+        }
+
+        impl #impl_generics ::std::str::FromStr for #ident #ty_generics #where_clause {
+            type Err = ::std::string::String;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    #(#values => ::std::result::Result::Ok(Self::#idents),)*
+                    _ => ::std::result::Result::Err(::std::format!(#error, s)),
+                }
+            }
+        }
+    })
+}
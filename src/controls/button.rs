@@ -1,32 +1,51 @@
 use super::{BuilderCxFn, BuilderFn, ControlRenderData, VanityControlBuilder};
 use super::{GetterVanityControlData, VanityControlData};
 use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
-use leptos::{RwSignal, Signal, View};
-use std::rc::Rc;
+use leptos::{
+    create_signal, spawn_local, MaybeSignal, RwSignal, Signal, SignalGetUntracked, SignalSet, View,
+};
+use std::{future::Future, pin::Pin, rc::Rc};
 use web_sys::MouseEvent;
 
 type ButtonAction<FD> = dyn Fn(MouseEvent, RwSignal<FD>) + 'static;
+type AsyncButtonAction<FD> =
+    dyn Fn(MouseEvent, RwSignal<FD>) -> Pin<Box<dyn Future<Output = ()>>> + 'static;
 
 /// Data used for the building button control.
 pub struct ButtonBuildData<FD: FormToolData> {
     pub action: Option<Rc<ButtonAction<FD>>>,
+    pub async_action: Option<Rc<AsyncButtonAction<FD>>>,
+    pub pending_text: Option<String>,
 }
 impl<FD: FormToolData> Default for ButtonBuildData<FD> {
     fn default() -> Self {
-        ButtonBuildData { action: None }
+        ButtonBuildData {
+            action: None,
+            async_action: None,
+            pending_text: None,
+        }
     }
 }
 impl<FD: FormToolData> Clone for ButtonBuildData<FD> {
     fn clone(&self) -> Self {
         ButtonBuildData {
             action: self.action.clone(),
+            async_action: self.async_action.clone(),
+            pending_text: self.pending_text.clone(),
         }
     }
 }
 
 /// Data used for the button control.
+#[derive(Clone)]
 pub struct ButtonData {
     pub action: Option<Rc<dyn Fn(MouseEvent)>>,
+    /// Whether the button's async action is still running.
+    pub pending: MaybeSignal<bool>,
+    /// The text announced to screen readers while the action is running.
+    ///
+    /// If `None`, the style's default is used.
+    pub pending_text: Option<String>,
 }
 
 impl<FD: FormToolData> VanityControlData<FD> for ButtonBuildData<FD> {
@@ -36,15 +55,44 @@ impl<FD: FormToolData> VanityControlData<FD> for ButtonBuildData<FD> {
         control: Rc<ControlRenderData<FS, Self>>,
         value_getter: Option<Signal<String>>,
     ) -> View {
-        let action = control.data.action.as_ref().map(|a| {
-            let a = a.clone();
-            let action = move |ev: MouseEvent| a(ev, fd);
-            Rc::new(action) as Rc<dyn Fn(MouseEvent)>
-        });
+        let mut pending = MaybeSignal::default();
+        let action = if let Some(ref async_action) = control.data.async_action {
+            let async_action = async_action.clone();
+            let (pending_signal, pending_set) = create_signal(false);
+            pending = MaybeSignal::Dynamic(pending_signal.into());
+            let action = move |ev: MouseEvent| {
+                // ignore clicks while the action is running
+                if pending_signal.get_untracked() {
+                    return;
+                }
+                pending_set.set(true);
+                let future = async_action(ev, fd);
+                spawn_local(async move {
+                    future.await;
+                    pending_set.set(false);
+                });
+            };
+            Some(Rc::new(action) as Rc<dyn Fn(MouseEvent)>)
+        } else {
+            control.data.action.as_ref().map(|a| {
+                let a = a.clone();
+                let action = move |ev: MouseEvent| a(ev, fd);
+                Rc::new(action) as Rc<dyn Fn(MouseEvent)>
+            })
+        };
 
         let new_control = ControlRenderData {
             styles: control.styles.clone(),
-            data: ButtonData { action },
+            conditional_styles: control.conditional_styles.clone(),
+            node_ref: control.node_ref,
+            tabindex: control.tabindex,
+            inline_errors: control.inline_errors,
+            native_validation: control.native_validation.clone(),
+            data: ButtonData {
+                action,
+                pending,
+                pending_text: control.data.pending_text.clone(),
+            },
         };
         let new_control = Rc::new(new_control);
         fs.button(new_control, value_getter)
@@ -81,8 +129,34 @@ impl<FD: FormToolData> VanityControlBuilder<FD, ButtonBuildData<FD>> {
     }
 
     /// Sets the action that is preformed when the button is clicked.
+    ///
+    /// This will overwrite any previous action.
     pub fn action(mut self, action: impl Fn(MouseEvent, RwSignal<FD>) + 'static) -> Self {
+        self.data.async_action = None;
         self.data.action = Some(Rc::new(action));
         self
     }
+
+    /// Sets an async action that is preformed when the button is clicked.
+    ///
+    /// The button is shown as pending until the future completes, and any
+    /// clicks while pending are ignored.
+    ///
+    /// This will overwrite any previous action.
+    pub fn async_action<Fut: Future<Output = ()> + 'static>(
+        mut self,
+        action: impl Fn(MouseEvent, RwSignal<FD>) -> Fut + 'static,
+    ) -> Self {
+        let action = move |ev, fd| Box::pin(action(ev, fd)) as Pin<Box<dyn Future<Output = ()>>>;
+        self.data.action = None;
+        self.data.async_action = Some(Rc::new(action));
+        self
+    }
+
+    /// Sets the text announced to screen readers while the button's async
+    /// action is running, like "Saving…".
+    pub fn pending_text(mut self, text: impl ToString) -> Self {
+        self.data.pending_text = Some(text.to_string());
+        self
+    }
 }
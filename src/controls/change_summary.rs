@@ -0,0 +1,96 @@
+use super::{BuilderCxFn, BuilderFn, ControlRenderData, VanityControlBuilder, VanityControlData};
+use crate::{
+    form::{changes, FormToolData},
+    form_builder::FormBuilder,
+    styles::FormStyle,
+};
+use leptos::{MaybeSignal, RwSignal, Signal, View};
+use std::rc::Rc;
+
+/// A field that has changed from its initial value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldChange {
+    /// The name of the field.
+    pub name: String,
+    /// The initial value of the field, as text.
+    pub old: String,
+    /// The current value of the field, as text.
+    pub new: String,
+}
+
+/// Data used for the change summary control.
+#[derive(Debug, Clone, Default)]
+pub struct ChangeSummaryData {
+    pub label: Option<MaybeSignal<String>>,
+    /// The fields that have changed from their initial values.
+    ///
+    /// This is filled in when the form is rendered.
+    pub changes: Option<Signal<Vec<FieldChange>>>,
+}
+
+impl<FD: FormToolData> VanityControlData<FD> for ChangeSummaryData {
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: Rc<ControlRenderData<FS, Self>>,
+        _value_getter: Option<Signal<String>>,
+    ) -> View {
+        let changes = control
+            .data
+            .changes
+            .unwrap_or_else(|| Signal::derive(Vec::new));
+        fs.change_summary(control, changes)
+    }
+}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a summary of the changed fields and adds it to the form.
+    ///
+    /// This lists the fields that have changed from their initial values,
+    /// with their old and new values, which is useful for confirmation
+    /// screens. See [`Form::changes`](crate::Form::changes).
+    pub fn change_summary(
+        mut self,
+        builder: impl BuilderFn<VanityControlBuilder<FD, ChangeSummaryData>>,
+    ) -> Self {
+        let control = builder(VanityControlBuilder::new(ChangeSummaryData::default()));
+        self.add_change_summary(control);
+        self
+    }
+
+    /// Builds a summary of the changed fields using the form's context and
+    /// adds it to the form.
+    pub fn change_summary_cx(
+        mut self,
+        builder: impl BuilderCxFn<VanityControlBuilder<FD, ChangeSummaryData>, FD::Context>,
+    ) -> Self {
+        let control = builder(
+            VanityControlBuilder::new(ChangeSummaryData::default()),
+            self.cx.clone(),
+        );
+        self.add_change_summary(control);
+        self
+    }
+
+    fn add_change_summary(&mut self, control: VanityControlBuilder<FD, ChangeSummaryData>) {
+        self.add_vanity_with_state(control, |data, render_state| {
+            data.changes = Some(changes(render_state.control_states));
+        });
+    }
+}
+
+impl<FD: FormToolData> VanityControlBuilder<FD, ChangeSummaryData> {
+    /// Sets the label for the change summary.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.label = Some(MaybeSignal::Static(label.to_string()));
+        self
+    }
+
+    /// Sets the label for the change summary to a signal.
+    ///
+    /// This is useful for labels that change, like localized labels.
+    pub fn labeled_signal(mut self, label: Signal<String>) -> Self {
+        self.data.label = Some(MaybeSignal::Dynamic(label));
+        self
+    }
+}
@@ -1,32 +1,90 @@
 use super::{
-    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidationState,
+    display::DisplayData, BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData,
+    ValidatedControlData, ValidationState,
 };
 use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
-use leptos::{RwSignal, Signal, SignalSetter, View};
+use leptos::{MaybeSignal, RwSignal, Signal, SignalGet, SignalSetter, View};
 use std::rc::Rc;
 
 /// Data used for the checkbox control.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct CheckboxData {
     pub name: String,
-    pub label: Option<String>,
+    pub label: Option<MaybeSignal<String>>,
+    /// The value that is submitted when the checkbox is checked.
+    pub checked_value: Option<String>,
+    /// The value that is submitted when the checkbox is unchecked.
+    ///
+    /// If this is `None`, nothing is submitted when unchecked.
+    pub unchecked_value: Option<String>,
+    /// The text shown for the checked and unchecked states when the form
+    /// is displayed rather than edited.
+    ///
+    /// If this is `None`, "Yes" and "No" are shown.
+    pub display_text: Option<(String, String)>,
+}
+
+impl CheckboxData {
+    /// Gets the text to show for the value when the form is displayed.
+    fn display_text(&self, checked: bool) -> String {
+        match (&self.display_text, checked) {
+            (Some((checked_text, _)), true) => checked_text.clone(),
+            (Some((_, unchecked_text)), false) => unchecked_text.clone(),
+            (None, true) => String::from("Yes"),
+            (None, false) => String::from("No"),
+        }
+    }
 }
 
 impl<FD: FormToolData> ControlData<FD> for CheckboxData {
     type ReturnType = bool;
 
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn value_text(&self, value: &Self::ReturnType) -> Option<String> {
+        Some(self.display_text(*value))
+    }
+
     fn render_control<FS: FormStyle>(
         fs: &FS,
         _fd: RwSignal<FD>,
         control: Rc<ControlRenderData<FS, Self>>,
         value_getter: Signal<Self::ReturnType>,
         value_setter: SignalSetter<Self::ReturnType>,
-        _validation_state: Signal<ValidationState>,
+        validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View {
-        fs.checkbox(control, value_getter, value_setter)
+        fs.checkbox(
+            control,
+            value_getter,
+            value_setter,
+            validation_state,
+            touched,
+        )
+    }
+
+    fn render_display<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: Rc<ControlRenderData<FS, Self>>,
+        value_getter: Signal<Self::ReturnType>,
+    ) -> View {
+        let control_clone = control.clone();
+        let value = move || control_clone.data.display_text(value_getter.get());
+        DisplayData::render(
+            fs,
+            &control,
+            &control.data.name,
+            control.data.label.as_ref(),
+            value,
+        )
     }
 }
 
+impl<FD: FormToolData> ValidatedControlData<FD> for CheckboxData {}
+
 impl<FD: FormToolData> FormBuilder<FD> {
     /// Builds a checkbox and adds it to the form.
     pub fn checkbox<FDT: Clone + PartialEq + 'static>(
@@ -53,7 +111,9 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, CheckboxData, FDT> {
     /// with.
     ///
     /// For checkbox controls, the value "checked" is sent or no key value
-    /// pair is sent.
+    /// pair is sent. To change what is sent, see
+    /// [`checked_value`](Self::checked_value) and
+    /// [`unchecked_value`](Self::unchecked_value).
     pub fn named(mut self, control_name: impl ToString) -> Self {
         self.data.name = control_name.to_string();
         self
@@ -61,7 +121,66 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, CheckboxData, FDT> {
 
     /// Sets the text of the checkbox's label.
     pub fn labeled(mut self, label: impl ToString) -> Self {
-        self.data.label = Some(label.to_string());
+        self.data.label = Some(MaybeSignal::Static(label.to_string()));
+        self
+    }
+
+    /// Sets the text of the checkbox's label to a signal.
+    ///
+    /// This is useful for labels that change, like localized labels.
+    pub fn labeled_signal(mut self, label: Signal<String>) -> Self {
+        self.data.label = Some(MaybeSignal::Dynamic(label));
+        self
+    }
+
+    /// Sets the value that is sent with the form when the checkbox is
+    /// checked.
+    pub fn checked_value(mut self, value: impl ToString) -> Self {
+        self.data.checked_value = Some(value.to_string());
+        self
+    }
+
+    /// Sets the value that is sent with the form when the checkbox is
+    /// unchecked.
+    ///
+    /// Normally, no key value pair is sent for an unchecked checkbox.
+    /// Setting this will make sure a value is always sent.
+    pub fn unchecked_value(mut self, value: impl ToString) -> Self {
+        self.data.unchecked_value = Some(value.to_string());
+        self
+    }
+
+    /// Sets the text shown for a checked and an unchecked checkbox when the
+    /// form is displayed rather than edited, like "Subscribed" and
+    /// "Not subscribed".
+    ///
+    /// This defaults to "Yes" and "No".
+    pub fn display_text(mut self, checked: impl ToString, unchecked: impl ToString) -> Self {
+        self.data.display_text = Some((checked.to_string(), unchecked.to_string()));
+        self
+    }
+}
+
+impl<FD: FormToolData> ControlBuilder<FD, CheckboxData, bool> {
+    /// Requires the checkbox to be checked for the form to submit, using
+    /// `msg` as the error message.
+    ///
+    /// This is useful for consent checkboxes, like "I agree to the terms".
+    /// This runs after any [`validation_fn`](Self::validation_fn).
+    pub fn required(mut self, msg: impl ToString) -> Self {
+        let msg = msg.to_string();
+        self.deferred_validations
+            .push(Box::new(move |_, getter, _| {
+                Rc::new(
+                    move |fd: &FD| {
+                        if getter(fd) {
+                            Ok(())
+                        } else {
+                            Err(msg.clone())
+                        }
+                    },
+                )
+            }));
         self
     }
 }
@@ -67,12 +67,15 @@ impl<FD: FormToolData> FormBuilder<FD> {
     /// This method does not give you any access to the validation system
     /// so you should not add controls to the form with this method.
     /// consider defining a custom component for this purpose.
+    ///
+    /// The `render_fn` will also run on the server when using SSR, so
+    /// browser-only APIs should only be used in event handlers or effects.
     pub fn raw_view(
         mut self,
         render_fn: impl Fn(Rc<FD::Style>, RwSignal<FD>, Rc<FD::Context>) -> View + 'static,
     ) -> Self {
         let cx = self.cx.clone();
-        let render_fn = move |fs: Rc<FD::Style>, fd: RwSignal<FD>| {
+        let render_fn = move |fs: Rc<FD::Style>, fd: RwSignal<FD>, _| {
             let view = render_fn(fs, fd, cx);
             (view, None)
         };
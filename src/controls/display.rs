@@ -0,0 +1,41 @@
+use super::ControlRenderData;
+use crate::styles::FormStyle;
+use leptos::{MaybeSignal, Signal, View};
+use std::rc::Rc;
+
+/// Data used to render a control as read only text, when the form is in
+/// [`FormMode::Display`](crate::FormMode::Display).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct DisplayData {
+    pub name: String,
+    pub label: Option<MaybeSignal<String>>,
+}
+
+impl DisplayData {
+    /// Renders a control as read only text with the form style's
+    /// [`display`](FormStyle::display) method.
+    ///
+    /// The styles of the control are kept, so the text takes up the same
+    /// space that the control would.
+    pub fn render<FS: FormStyle, C>(
+        fs: &FS,
+        control: &ControlRenderData<FS, C>,
+        name: &str,
+        label: Option<&MaybeSignal<String>>,
+        value: impl Fn() -> String + 'static,
+    ) -> View {
+        let render_data = ControlRenderData {
+            data: DisplayData {
+                name: name.to_string(),
+                label: label.cloned(),
+            },
+            styles: control.styles.clone(),
+            conditional_styles: control.conditional_styles.clone(),
+            node_ref: None,
+            tabindex: None,
+            inline_errors: true,
+            native_validation: None,
+        };
+        fs.display(Rc::new(render_data), Signal::derive(value))
+    }
+}
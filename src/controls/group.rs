@@ -1,48 +1,262 @@
-use std::rc::Rc;
+use std::{cell::OnceCell, rc::Rc};
 
-use super::{ControlRenderData, ValidationCb};
+use super::{
+    ControlRenderData, RenderState, ShowWhenFn, SimulateFn, SimulateState, ValidationCb,
+    ValidationState,
+};
 use crate::styles::FormStyle;
 use crate::{form::FormToolData, form_builder::FormBuilder};
-use leptos::{CollectView, RwSignal};
+use leptos::{
+    create_rw_signal, view, CollectView, IntoView, RwSignal, Show, Signal, SignalGet, SignalSet,
+    SignalSetter, View,
+};
+
+/// A function that adds the controls of a section to the section's
+/// builder.
+pub type SectionBuilder<FD> = Box<dyn Fn(FormBuilder<FD>) -> FormBuilder<FD>>;
+
+/// Data used for the titled group.
+#[derive(Clone)]
+pub struct TitledGroupData {
+    /// The title of the group.
+    pub title: String,
+    /// The number of controls in the group that currently have a parse or
+    /// validation error.
+    pub error_count: Signal<usize>,
+    /// The view of the group's components.
+    pub inner: View,
+}
+
+/// Data used for a section of an accordion.
+#[derive(Clone)]
+pub struct AccordionSectionData {
+    /// The title of the section.
+    pub title: String,
+    /// The number of controls in the section that currently have a parse
+    /// or validation error.
+    pub error_count: Signal<usize>,
+    /// Whether the section is open.
+    pub open: Signal<bool>,
+    /// Opens or closes the section. Opening a section closes the other
+    /// sections of the accordion.
+    pub set_open: SignalSetter<bool>,
+    /// The view of the section's components.
+    pub inner: View,
+}
 
 impl<FD: FormToolData> FormBuilder<FD> {
     /// Creates a form group.
     ///
     /// This creates a subsection of the form that controls can be added to
     /// like a normal form.
-    pub fn group(mut self, builder: impl Fn(FormBuilder<FD>) -> FormBuilder<FD>) -> Self {
+    pub fn group(self, builder: impl Fn(FormBuilder<FD>) -> FormBuilder<FD>) -> Self {
+        self.add_group(builder, None, |fs, render_data, _| fs.group(render_data))
+    }
+
+    /// Creates a form group that is only rendered when `when` returns true.
+    ///
+    /// This is like [`group`](Self::group)(), but hides the entire group,
+    /// like setting `show_when` on every control in it. Validations for the
+    /// controls in the group DO NOT run while it is hidden.
+    pub fn group_when(
+        self,
+        when: impl Fn(Signal<FD>, Rc<FD::Context>) -> bool + 'static,
+        builder: impl Fn(FormBuilder<FD>) -> FormBuilder<FD>,
+    ) -> Self {
+        self.add_group(builder, Some(Rc::new(when)), |fs, render_data, _| {
+            fs.group(render_data)
+        })
+    }
+
+    /// Creates a form group with a title.
+    ///
+    /// This is like [`group`](Self::group)(), but the group also shows the
+    /// title and the number of controls in the group that have errors. This
+    /// helps users find their mistakes in long, sectioned forms.
+    pub fn titled_group(
+        self,
+        title: impl ToString,
+        builder: impl Fn(FormBuilder<FD>) -> FormBuilder<FD>,
+    ) -> Self {
+        let title = title.to_string();
+        self.add_group(builder, None, move |fs, render_data, error_count| {
+            let render_data = Rc::new(ControlRenderData {
+                data: TitledGroupData {
+                    title,
+                    error_count,
+                    inner: render_data.data.clone(),
+                },
+                styles: render_data.styles.clone(),
+                conditional_styles: render_data.conditional_styles.clone(),
+                node_ref: None,
+                tabindex: None,
+                inline_errors: true,
+                native_validation: None,
+            });
+            fs.titled_group(render_data)
+        })
+    }
+
+    /// Creates an accordion of titled sections, where only one section is
+    /// open at a time.
+    ///
+    /// Each section is built like a [`group`](Self::group)(). Opening a
+    /// section closes the others, and the first section starts open. The
+    /// controls of closed sections are still rendered, just collapsed, so
+    /// they are still sent and validated when the form is submitted.
+    pub fn accordion(self, sections: Vec<(impl ToString, SectionBuilder<FD>)>) -> Self {
+        // the index of the open section, shared by the sections once rendered
+        let open_index = Rc::new(OnceCell::new());
+        sections
+            .into_iter()
+            .enumerate()
+            .fold(self, |form_builder, (index, (title, builder))| {
+                let title = title.to_string();
+                let open_index = open_index.clone();
+                form_builder.add_group(builder, None, move |fs, render_data, error_count| {
+                    let open_index = *open_index.get_or_init(|| create_rw_signal(Some(0)));
+                    let open = Signal::derive(move || open_index.get() == Some(index));
+                    let set_open = SignalSetter::map(move |open: bool| {
+                        open_index.set(open.then_some(index));
+                    });
+                    let render_data = Rc::new(ControlRenderData {
+                        data: AccordionSectionData {
+                            title,
+                            error_count,
+                            open,
+                            set_open,
+                            inner: render_data.data.clone(),
+                        },
+                        styles: render_data.styles.clone(),
+                        conditional_styles: render_data.conditional_styles.clone(),
+                        node_ref: None,
+                        tabindex: None,
+                        inline_errors: true,
+                        native_validation: None,
+                    });
+                    fs.accordion_section(render_data)
+                })
+            })
+    }
+
+    /// Builds a group with the given builder, and adds it to the form.
+    ///
+    /// The `render` function is given the group's render data and the
+    /// number of child controls with errors. If `show_when` is given, the
+    /// group is only shown, and its controls only validated, while it
+    /// returns true.
+    fn add_group(
+        mut self,
+        builder: impl Fn(FormBuilder<FD>) -> FormBuilder<FD>,
+        show_when: Option<Rc<dyn ShowWhenFn<FD, FD::Context>>>,
+        render: impl FnOnce(&FD::Style, Rc<ControlRenderData<FD::Style, View>>, Signal<usize>) -> View
+            + 'static,
+    ) -> Self {
         let mut group_builder = FormBuilder::new_group(self.cx.clone());
+        group_builder.conditions = self.conditions.clone();
         group_builder = builder(group_builder);
 
-        for validation in group_builder.validations {
+        let validations =
+            FormBuilder::gate_validations(group_builder.validations, group_builder.validation_gate);
+        for validation in validations {
+            let validation = match show_when.clone() {
+                Some(show_when) => {
+                    // validations always succeed while the group is hidden
+                    let cx = self.cx.clone();
+                    let validation = move |fd: &FD| {
+                        if !FormBuilder::is_shown(&*show_when, fd, &cx) {
+                            return Ok(());
+                        }
+                        validation(fd)
+                    };
+                    Rc::new(validation)
+                }
+                None => validation,
+            };
             self.validations.push(validation);
         }
+        for exclusion in group_builder.exclusions {
+            self.exclusions.push(exclusion);
+        }
+        for (name, simulator) in group_builder.simulators {
+            let simulator: Rc<dyn SimulateFn<FD>> = match show_when.clone() {
+                Some(show_when) => {
+                    // the controls can't be changed while the group is hidden
+                    let cx = self.cx.clone();
+                    let simulator = move |fd: &mut FD, value, state: &SimulateState<FD>| {
+                        if !FormBuilder::is_shown(&*show_when, fd, &cx) {
+                            return ValidationState::Passed;
+                        }
+                        simulator(fd, value, state)
+                    };
+                    Rc::new(simulator)
+                }
+                None => simulator,
+            };
+            self.simulators.push((name, simulator));
+        }
+        self.field_names.extend(group_builder.field_names);
 
-        let render_fn = move |fs: Rc<FD::Style>, fd: RwSignal<FD>| {
-            let (views, validation_cbs): (Vec<_>, Vec<_>) = group_builder
-                .render_fns
-                .into_iter()
-                .map(|r_fn| r_fn(fs.clone(), fd))
-                .unzip();
+        let cx = self.cx.clone();
+        let render_fn =
+            move |fs: Rc<FD::Style>, fd: RwSignal<FD>, render_state: RenderState<FD>| {
+                // the states that the children register are the group's states
+                let control_states = render_state.control_states;
+                let first_state = control_states.with_value(|states| states.len());
+                let (views, validation_cbs): (Vec<_>, Vec<_>) = group_builder
+                    .render_fns
+                    .into_iter()
+                    .map(|r_fn| r_fn(fs.clone(), fd, render_state.clone()))
+                    .unzip();
+                let last_state = control_states.with_value(|states| states.len());
 
-            let render_data = Rc::new(ControlRenderData {
-                data: views.collect_view(),
-                styles: group_builder.styles,
-            });
+                let error_count = Signal::derive(move || {
+                    control_states.with_value(|states| {
+                        states[first_state..last_state]
+                            .iter()
+                            .filter(|state| state.validation_state.get().is_err())
+                            .count()
+                    })
+                });
 
-            let view = fs.group(render_data.clone());
+                let render_data = Rc::new(ControlRenderData {
+                    data: views.collect_view(),
+                    styles: group_builder.styles,
+                    conditional_styles: Vec::new(),
+                    node_ref: None,
+                    tabindex: None,
+                    inline_errors: true,
+                    native_validation: None,
+                });
 
-            let validation_cb = move || {
-                let mut success = true;
-                for validation in validation_cbs.iter().flatten() {
-                    if !validation() {
-                        success = false;
+                let view = render(&fs, render_data, error_count).into_view();
+                let (view, shown) = match show_when {
+                    Some(show_when) => {
+                        let shown = move || show_when(fd.into(), cx.clone());
+                        let shown_clone = shown.clone();
+                        let view = view! { <Show when=shown_clone>{view.clone()}</Show> };
+                        (view, Some(shown))
                     }
-                }
-                success
+                    None => (view, None),
+                };
+
+                let validation_cb = move || {
+                    // validation for hidden groups always succeeds
+                    if let Some(ref shown) = shown {
+                        if !shown() {
+                            return true;
+                        }
+                    }
+                    let mut success = true;
+                    for validation in validation_cbs.iter().flatten() {
+                        if !validation() {
+                            success = false;
+                        }
+                    }
+                    success
+                };
+                (view, Some(Box::new(validation_cb) as Box<dyn ValidationCb>))
             };
-            (view, Some(Box::new(validation_cb) as Box<dyn ValidationCb>))
-        };
 
         self.render_fns.push(Box::new(render_fn));
         self
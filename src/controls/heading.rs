@@ -19,6 +19,40 @@ pub enum HeadingLevel {
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct HeadingData {
     pub level: HeadingLevel,
+    /// The `id` to put on the heading element, for linking to it.
+    pub anchor: Option<String>,
+    /// Whether to generate the `id` from the title when no `anchor` is set.
+    pub auto_anchor: bool,
+}
+
+impl HeadingData {
+    /// Gets the `id` that the heading element should have, given the
+    /// heading's title.
+    ///
+    /// This is the explicit anchor if one was set, otherwise a slug of the
+    /// title if [`auto_anchor`](VanityControlBuilder::auto_anchor)() was
+    /// used.
+    pub fn anchor_id(&self, title: &str) -> Option<String> {
+        if self.anchor.is_some() {
+            return self.anchor.clone();
+        }
+        if !self.auto_anchor {
+            return None;
+        }
+
+        let mut slug = String::with_capacity(title.len());
+        for c in title.chars() {
+            if c.is_alphanumeric() {
+                slug.extend(c.to_lowercase());
+            } else if !slug.is_empty() && !slug.ends_with('-') {
+                slug.push('-');
+            }
+        }
+        while slug.ends_with('-') {
+            slug.pop();
+        }
+        Some(slug)
+    }
 }
 
 impl<FD: FormToolData> VanityControlData<FD> for HeadingData {
@@ -56,6 +90,23 @@ impl<FD: FormToolData> VanityControlBuilder<FD, HeadingData> {
         self
     }
 
+    /// Sets the `id` of the rendered heading, so that it can be linked to
+    /// (i.e. `#section-shipping`).
+    pub fn anchor(mut self, anchor: impl ToString) -> Self {
+        self.data.anchor = Some(anchor.to_string());
+        self
+    }
+
+    /// Generates the `id` of the rendered heading from its title.
+    ///
+    /// For example, a title of "Shipping Address" gets the id
+    /// `shipping-address`. An explicit [`anchor`](Self::anchor)() takes
+    /// precedence over this.
+    pub fn auto_anchor(mut self) -> Self {
+        self.data.auto_anchor = true;
+        self
+    }
+
     /// Sets this title to be a `h1`.
     pub fn h1(mut self) -> Self {
         self.data.level = HeadingLevel::H1;
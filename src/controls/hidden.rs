@@ -1,9 +1,10 @@
 use super::{
-    BuilderCxFn, BuilderFn, ControlRenderData, GetterVanityControlData, VanityControlBuilder,
+    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData,
+    GetterVanityControlData, ValidatedControlData, ValidationState, VanityControlBuilder,
     VanityControlData,
 };
 use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
-use leptos::{Signal, View};
+use leptos::{RwSignal, Signal, SignalSetter, View};
 use std::rc::Rc;
 
 /// Data used for the hidden control.
@@ -13,6 +14,10 @@ pub struct HiddenData {
 }
 
 impl<FD: FormToolData> VanityControlData<FD> for HiddenData {
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
     fn render_control<FS: FormStyle>(
         fs: &FS,
         _fd: leptos::prelude::RwSignal<FD>,
@@ -24,6 +29,48 @@ impl<FD: FormToolData> VanityControlData<FD> for HiddenData {
 }
 impl<FD: FormToolData> GetterVanityControlData<FD> for HiddenData {}
 
+/// Data used for the hidden field control.
+///
+/// Unlike [`HiddenData`], this is bound to a field of the form data with a
+/// getter, setter, and parse functions, so it can hold any type that can
+/// be turned into a string, and be validated.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct HiddenFieldData {
+    pub name: String,
+}
+
+impl<FD: FormToolData> ControlData<FD> for HiddenFieldData {
+    type ReturnType = String;
+
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: Rc<ControlRenderData<FS, Self>>,
+        value_getter: Signal<Self::ReturnType>,
+        _value_setter: SignalSetter<Self::ReturnType>,
+        _validation_state: Signal<ValidationState>,
+        _touched: Signal<bool>,
+    ) -> View {
+        let render_data = ControlRenderData {
+            data: HiddenData {
+                name: control.data.name.clone(),
+            },
+            styles: control.styles.clone(),
+            conditional_styles: control.conditional_styles.clone(),
+            node_ref: control.node_ref,
+            tabindex: control.tabindex,
+            inline_errors: control.inline_errors,
+            native_validation: control.native_validation.clone(),
+        };
+        fs.hidden(Rc::new(render_data), Some(value_getter))
+    }
+}
+impl<FD: FormToolData> ValidatedControlData<FD> for HiddenFieldData {}
+
 impl<FD: FormToolData> FormBuilder<FD> {
     /// Builds a hidden form control and adds it to the form.
     ///
@@ -44,6 +91,27 @@ impl<FD: FormToolData> FormBuilder<FD> {
     ) -> Self {
         self.new_vanity_cx(builder)
     }
+
+    /// Builds a hidden field control and adds it to the form.
+    ///
+    /// Like [`hidden`](Self::hidden), this has no UI, but the value is
+    /// parsed into the form data like an interactive control. This is useful
+    /// for round-tripping typed values, like ids or JSON, through the form.
+    pub fn hidden_field<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, HiddenFieldData, FDT>>,
+    ) -> Self {
+        self.new_control(builder)
+    }
+
+    /// Builds a hidden field control using the form's context and adds it
+    /// to the form.
+    pub fn hidden_field_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, HiddenFieldData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(builder)
+    }
 }
 
 impl<FD: FormToolData> VanityControlBuilder<FD, HiddenData> {
@@ -57,3 +125,15 @@ impl<FD: FormToolData> VanityControlBuilder<FD, HiddenData> {
         self
     }
 }
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, HiddenFieldData, FDT> {
+    /// Sets the name of the hidden field.
+    ///
+    /// This is used for the html element's "name" attribute.
+    /// In forms, the name attribute is the key that the data is sent
+    /// with.
+    pub fn named(mut self, control_name: impl ToString) -> Self {
+        self.data.name = control_name.to_string();
+        self
+    }
+}
@@ -1,19 +1,30 @@
-use crate::{form::FormToolData, styles::FormStyle};
-use leptos::{RwSignal, Signal, SignalSetter, View};
-use std::{fmt::Display, rc::Rc, str::FromStr};
+use crate::{
+    form::{ErrorDisplay, FormMode, FormToolData},
+    styles::FormStyle,
+    validation_builder::{NativeValidation, ValidationBuilder},
+};
+use leptos::{
+    create_memo, html::AnyElement, NodeRef, RwSignal, Signal, SignalSetter, SignalWith,
+    StoredValue, View,
+};
+use std::{any::Any, fmt::Display, future::Future, pin::Pin, rc::Rc, str::FromStr};
 
 pub mod button;
+pub mod change_summary;
 pub mod checkbox;
 pub mod custom;
+pub mod display;
 pub mod group;
 pub mod heading;
 pub mod hidden;
 pub mod output;
+pub mod progress;
 pub mod radio_buttons;
 pub mod select;
 pub mod slider;
 pub mod spacer;
 pub mod stepper;
+pub mod subform;
 pub mod submit;
 pub mod text_area;
 pub mod text_input;
@@ -21,14 +32,32 @@ pub mod text_input;
 pub trait BuilderFn<B>: Fn(B) -> B {}
 pub trait BuilderCxFn<B, CX>: Fn(B, Rc<CX>) -> B {}
 pub trait ValidationFn<FD: ?Sized>: Fn(&FD) -> Result<(), String> + 'static {}
+pub trait FieldsValidationFn<FD: ?Sized>:
+    Fn(&FD) -> Result<(), Vec<(String, String)>> + 'static
+{
+}
 pub trait ValidationCb: Fn() -> bool + 'static {}
 pub trait ParseFn<CR, FDT>: Fn(CR) -> Result<FDT, String> + 'static {}
 pub trait UnparseFn<CR, FDT>: Fn(FDT) -> CR + 'static {}
 pub trait FieldGetter<FD, FDT>: Fn(&FD) -> FDT + 'static {}
 pub trait FieldSetter<FD, FDT>: Fn(&mut FD, FDT) + 'static {}
 pub trait ShowWhenFn<FD: 'static, CX>: Fn(Signal<FD>, Rc<CX>) -> bool + 'static {}
+pub trait ExcludeWhenFn<FD, FDT>: Fn(&FD) -> Option<FDT> + 'static {}
+pub trait ExcludeFn<FD>: Fn(&mut FD) + 'static {}
+pub trait CheckFn<FD>: Fn(&FD) -> bool + 'static {}
+pub trait NormalizeFn<CR>: Fn(CR) -> CR + 'static {}
+pub trait FieldChangeFn<FD>: Fn(&str, &FD) + 'static {}
+pub trait SimulateFn<FD>:
+    Fn(&mut FD, Box<dyn Any>, &SimulateState<FD>) -> ValidationState + 'static
+{
+}
+pub trait ParseErrorFn: Fn(&str) -> String + 'static {}
+pub trait ConfirmFn<FD>: Fn(&FD) -> bool + 'static {}
+pub trait ConfirmAsyncFn<FD>: Fn(&FD) -> Pin<Box<dyn Future<Output = bool>>> + 'static {}
+pub trait DepsFn<FD: 'static>: Fn(RwSignal<FD>) -> Signal<()> + 'static {}
+pub trait WrapFn: Fn(View) -> View + 'static {}
 pub trait RenderFn<FS, FD: 'static>:
-    FnOnce(Rc<FS>, RwSignal<FD>) -> (View, Option<Box<dyn ValidationCb>>) + 'static
+    FnOnce(Rc<FS>, RwSignal<FD>, RenderState<FD>) -> (View, Option<Box<dyn ValidationCb>>) + 'static
 {
 }
 
@@ -36,14 +65,36 @@ pub trait RenderFn<FS, FD: 'static>:
 impl<B, T> BuilderFn<B> for T where T: Fn(B) -> B {}
 impl<B, CX, T> BuilderCxFn<B, CX> for T where T: Fn(B, Rc<CX>) -> B {}
 impl<FDT, T> ValidationFn<FDT> for T where T: Fn(&FDT) -> Result<(), String> + 'static {}
+impl<FD, T> FieldsValidationFn<FD> for T where
+    T: Fn(&FD) -> Result<(), Vec<(String, String)>> + 'static
+{
+}
 impl<T> ValidationCb for T where T: Fn() -> bool + 'static {}
 impl<CR, FDT, F> ParseFn<CR, FDT> for F where F: Fn(CR) -> Result<FDT, String> + 'static {}
 impl<CR, FDT, F> UnparseFn<CR, FDT> for F where F: Fn(FDT) -> CR + 'static {}
 impl<FD, FDT, F> FieldGetter<FD, FDT> for F where F: Fn(&FD) -> FDT + 'static {}
 impl<FD, FDT, F> FieldSetter<FD, FDT> for F where F: Fn(&mut FD, FDT) + 'static {}
 impl<FD: 'static, CX, F> ShowWhenFn<FD, CX> for F where F: Fn(Signal<FD>, Rc<CX>) -> bool + 'static {}
+impl<FD, FDT, F> ExcludeWhenFn<FD, FDT> for F where F: Fn(&FD) -> Option<FDT> + 'static {}
+impl<FD, F> ExcludeFn<FD> for F where F: Fn(&mut FD) + 'static {}
+impl<FD, F> CheckFn<FD> for F where F: Fn(&FD) -> bool + 'static {}
+impl<CR, F> NormalizeFn<CR> for F where F: Fn(CR) -> CR + 'static {}
+impl<FD, F> FieldChangeFn<FD> for F where F: Fn(&str, &FD) + 'static {}
+impl<FD, F> SimulateFn<FD> for F where
+    F: Fn(&mut FD, Box<dyn Any>, &SimulateState<FD>) -> ValidationState + 'static
+{
+}
+impl<F> ParseErrorFn for F where F: Fn(&str) -> String + 'static {}
+impl<FD, F> ConfirmFn<FD> for F where F: Fn(&FD) -> bool + 'static {}
+impl<FD, F> ConfirmAsyncFn<FD> for F where
+    F: Fn(&FD) -> Pin<Box<dyn Future<Output = bool>>> + 'static
+{
+}
+impl<FD: 'static, F> DepsFn<FD> for F where F: Fn(RwSignal<FD>) -> Signal<()> + 'static {}
+impl<F> WrapFn for F where F: Fn(View) -> View + 'static {}
 impl<FS, FD: 'static, F> RenderFn<FS, FD> for F where
-    F: FnOnce(Rc<FS>, RwSignal<FD>) -> (View, Option<Box<dyn ValidationCb>>) + 'static
+    F: FnOnce(Rc<FS>, RwSignal<FD>, RenderState<FD>) -> (View, Option<Box<dyn ValidationCb>>)
+        + 'static
 {
 }
 
@@ -76,6 +127,22 @@ impl ValidationState {
         }
     }
 
+    /// Takes the error message if there is a parse error.
+    pub fn take_parse_msg(self) -> Option<String> {
+        match self {
+            ValidationState::ParseError(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// Takes the error message if there is a validation error.
+    pub fn take_validation_msg(self) -> Option<String> {
+        match self {
+            ValidationState::ValidationError(e) => Some(e),
+            _ => None,
+        }
+    }
+
     /// Returns true if self is `Passed`.
     pub fn is_passed(&self) -> bool {
         matches!(self, ValidationState::Passed)
@@ -96,17 +163,357 @@ impl ValidationState {
     }
 }
 
+/// The state of a rendered control that the form keeps track of.
+#[derive(Clone)]
+pub struct ControlState {
+    /// The name of the control.
+    pub(crate) name: String,
+    /// The validation state of the control.
+    pub(crate) validation_state: Signal<ValidationState>,
+    /// An error set on the control by a validation of the whole form.
+    pub(crate) external_error: RwSignal<Option<String>>,
+    /// Whether the user has interacted with the control.
+    pub(crate) touched: Signal<bool>,
+    /// A reference to the outermost element of the rendered control.
+    pub(crate) node_ref: NodeRef<AnyElement>,
+    /// A reference to the control's primary input element.
+    pub(crate) input_ref: NodeRef<AnyElement>,
+    /// The names of the control's checks, and whether they pass.
+    pub(crate) checklist: Signal<Vec<(String, bool)>>,
+    /// Whether the current value passes the control's validation.
+    ///
+    /// This is `None` if the control has no validation, or is hidden, so it
+    /// does not count towards the form's completion.
+    pub(crate) complete: Signal<Option<bool>>,
+    /// The old and new text of the control's value, if it has changed from
+    /// its initial value.
+    pub(crate) change: Signal<Option<(String, String)>>,
+    /// A `Signal` of the control's return type, with what is currently in
+    /// the control, even if it failed to parse.
+    pub(crate) raw_value: Rc<dyn Any>,
+}
+
+/// The form wide state that is given to every control when the form is
+/// rendered.
+pub struct RenderState<FD> {
+    /// The state of all the named controls in the form.
+    pub(crate) control_states: StoredValue<Vec<ControlState>>,
+    /// How the controls are rendered.
+    pub(crate) mode: FormMode,
+    /// Where the error messages are shown.
+    pub(crate) error_display: ErrorDisplay,
+    /// The function to call when a named field is changed by the user.
+    pub(crate) on_field_change: Option<Rc<dyn FieldChangeFn<FD>>>,
+    /// The function to wrap the view of every control with.
+    pub(crate) wrap_each: Option<Rc<dyn WrapFn>>,
+    /// The function to format parse errors that have no custom message.
+    pub(crate) parse_error_fn: Option<Rc<dyn ParseErrorFn>>,
+    /// Whether the form is being submitted.
+    pub(crate) submitting: Signal<bool>,
+    /// Whether to set the native html validation attributes on controls.
+    pub(crate) native_validation: bool,
+}
+
+impl<FD> RenderState<FD> {
+    /// Wraps the view of a control with the form's
+    /// [`wrap_each`](crate::FormBuilder::wrap_each) function, if there is
+    /// one.
+    pub(crate) fn wrap(wrap_each: &Option<Rc<dyn WrapFn>>, view: View) -> View {
+        match wrap_each {
+            Some(wrap_each) => wrap_each(view),
+            None => view,
+        }
+    }
+}
+
+impl<FD> Clone for RenderState<FD> {
+    fn clone(&self) -> Self {
+        RenderState {
+            control_states: self.control_states,
+            mode: self.mode,
+            error_display: self.error_display,
+            on_field_change: self.on_field_change.clone(),
+            wrap_each: self.wrap_each.clone(),
+            parse_error_fn: self.parse_error_fn.clone(),
+            submitting: self.submitting,
+            native_validation: self.native_validation,
+        }
+    }
+}
+
+/// The form wide state that is given to every control when simulating
+/// input with a [`FormSimulator`](crate::FormSimulator).
+pub struct SimulateState<FD> {
+    /// The function to format parse errors that have no custom message.
+    pub(crate) parse_error_fn: Option<Rc<dyn ParseErrorFn>>,
+    /// The validations of the whole form, which are gated by the form's
+    /// validation gate.
+    pub(crate) validations: Vec<Rc<dyn FieldsValidationFn<FD>>>,
+}
+
+impl<FD> SimulateState<FD> {
+    /// Gets the first error that the validations of the whole form have for
+    /// the control with the given name.
+    pub(crate) fn field_error(&self, fd: &FD, name: &str) -> Option<String> {
+        self.validations.iter().find_map(|validation| {
+            let errors = validation(fd).err()?;
+            errors
+                .into_iter()
+                .find(|(field, _)| field == name)
+                .map(|(_, msg)| msg)
+        })
+    }
+}
+
 /// The possibilities for when a control updates the form data.
+///
+/// This can be set per control with the control builder's `update_on`
+/// method.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub enum UpdateEvent {
+    /// Updates when the control loses focus.
     OnFocusout,
+    /// Updates on every keystroke or other input.
     OnInput,
+    /// Updates when the value is committed by the user, usually when
+    /// pressing enter or when the control loses focus.
     #[default]
     OnChange,
 }
 
+/// How the browser, mostly on mobile, automatically capitalizes what the
+/// user types into a text control.
+///
+/// This can be set per control with the control builder's `autocapitalize`
+/// method.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Autocapitalize {
+    /// Nothing is capitalized. Useful for usernames, emails, and codes.
+    None,
+    /// The first letter of each sentence is capitalized.
+    Sentences,
+    /// The first letter of each word is capitalized.
+    Words,
+    /// Every letter is capitalized.
+    Characters,
+}
+
+impl Autocapitalize {
+    /// Gets the value of the `autocapitalize` attribute.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Autocapitalize::None => "none",
+            Autocapitalize::Sentences => "sentences",
+            Autocapitalize::Words => "words",
+            Autocapitalize::Characters => "characters",
+        }
+    }
+}
+
+/// The ways that whitespace can be normalized in a text control's value.
+///
+/// This can be set per control with the control builder's `whitespace`
+/// method.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum WhitespaceMode {
+    /// The whitespace is left as is.
+    #[default]
+    None,
+    /// The leading and trailing whitespace is removed.
+    Trim,
+    /// The leading and trailing whitespace is removed, and any other runs
+    /// of whitespace are replaced with a single space.
+    Collapse,
+    /// All the whitespace is removed.
+    StripAll,
+}
+impl WhitespaceMode {
+    /// Applies this whitespace mode to the given string.
+    pub fn apply(&self, value: &str) -> String {
+        match self {
+            WhitespaceMode::None => value.to_string(),
+            WhitespaceMode::Trim => value.trim().to_string(),
+            WhitespaceMode::Collapse => value.split_whitespace().collect::<Vec<_>>().join(" "),
+            WhitespaceMode::StripAll => value.chars().filter(|c| !c.is_whitespace()).collect(),
+        }
+    }
+}
+
+/// A scalar type that can be parsed from, and unparsed to, the string a
+/// control returns.
+///
+/// This is implemented for [`bool`], [`char`], and the integer and float
+/// types, and is used by the control builder's `parse_primitive` method.
+pub trait Primitive: Sized {
+    /// Parses the value from the string a control returns.
+    fn parse_primitive(value: &str) -> Result<Self, String>;
+    /// Turns the value back into a string for the control.
+    fn unparse_primitive(&self) -> String;
+}
+
+impl Primitive for bool {
+    /// Parses `"true"`, `"on"`, `"yes"` and `"1"` as `true`, and `"false"`,
+    /// `"off"`, `"no"`, `"0"` and the empty string as `false`, ignoring case.
+    fn parse_primitive(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "true" | "on" | "yes" | "1" => Ok(true),
+            "false" | "off" | "no" | "0" | "" => Ok(false),
+            _ => Err(format!("'{}' is not true or false", value)),
+        }
+    }
+
+    fn unparse_primitive(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Primitive for char {
+    fn parse_primitive(value: &str) -> Result<Self, String> {
+        value.parse::<char>().map_err(|e| e.to_string())
+    }
+
+    fn unparse_primitive(&self) -> String {
+        self.to_string()
+    }
+}
+
+macro_rules! impl_primitive {
+    ($($t:ty),*) => {
+        $(
+            impl Primitive for $t {
+                fn parse_primitive(value: &str) -> Result<Self, String> {
+                    value.trim().parse::<$t>().map_err(|e| e.to_string())
+                }
+
+                fn unparse_primitive(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// The factor that the value shown in a number control is multiplied by to
+/// get the value that is stored, like 1000 for showing kilograms while
+/// storing grams.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScaleFactor(f64);
+
+// the factor is always finite, so it is never NaN
+impl Eq for ScaleFactor {}
+
+impl ScaleFactor {
+    /// Creates a new [`ScaleFactor`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is zero or not finite.
+    pub fn new(factor: f64) -> Self {
+        if !(factor.is_finite() && factor != 0.0) {
+            panic!(
+                "Invalid scale factor ({}): it must be finite and not zero",
+                factor
+            );
+        }
+        ScaleFactor(factor)
+    }
+
+    /// Gets the factor.
+    pub fn factor(self) -> f64 {
+        self.0
+    }
+
+    /// Converts a value shown to the user into the value that is stored.
+    ///
+    /// Values that are not numbers are returned unchanged.
+    pub fn stored_value(self, shown: &str) -> String {
+        match f64::parse_primitive(shown) {
+            Ok(value) => Self::format(value * self.0),
+            Err(_) => shown.to_string(),
+        }
+    }
+
+    /// Converts a stored value into the value shown to the user.
+    ///
+    /// Values that are not numbers are returned unchanged.
+    pub fn shown_value(self, stored: &str) -> String {
+        match f64::parse_primitive(stored) {
+            Ok(value) => Self::format(value / self.0),
+            Err(_) => stored.to_string(),
+        }
+    }
+
+    /// Formats a scaled value, rounding away the floating point error from
+    /// scaling so that integers still parse.
+    fn format(value: f64) -> String {
+        ((value * 1e9).round() / 1e9).to_string()
+    }
+}
+
+/// Appends the unit to the value, if there is one.
+pub(crate) fn with_unit(value: String, unit: &Option<String>) -> String {
+    match unit {
+        Some(unit) if !value.is_empty() => format!("{} {}", value, unit),
+        _ => value,
+    }
+}
+
+/// A type with a fixed set of values to choose from, like an enum.
+///
+/// This lets the options of a choice control, like radio buttons or a
+/// select, be generated from the type with the control builder's
+/// `options_from` method, so the options can't drift from the type.
+pub trait FormOptions: Sized {
+    /// Gets all the values of the type, in the order they are shown.
+    fn variants() -> Vec<Self>;
+
+    /// Gets the text shown to the user for this value.
+    fn display(&self) -> String;
+
+    /// Gets the string that this value is sent with.
+    ///
+    /// This must be unique among the variants. Defaults to the
+    /// [`display`](Self::display) text.
+    fn value(&self) -> String {
+        self.display()
+    }
+
+    /// Finds the variant that is sent with the given string.
+    fn from_value(value: &str) -> Option<Self> {
+        Self::variants().into_iter().find(|v| v.value() == value)
+    }
+}
+
+impl<FD, C, FDT> ControlBuilder<FD, C, FDT>
+where
+    FD: FormToolData,
+    C: ControlData<FD, ReturnType = String>,
+    FDT: FormOptions,
+{
+    /// Sets the parse functions to turn the value of a [`FormOptions`]
+    /// variant back into the variant, and vice versa.
+    pub(crate) fn parse_options(&mut self) {
+        self.parse_fn = Some(Box::new(|control_return_value: String| {
+            FDT::from_value(&control_return_value)
+                .ok_or_else(|| format!("'{}' is not a valid option", control_return_value))
+        }));
+        self.unparse_fn = Some(Box::new(|field: FDT| field.value()));
+        self.custom_parse_msg = false;
+    }
+}
+
 /// A trait for the data needed to render an read-only control.
 pub trait VanityControlData<FD: FormToolData>: 'static {
+    /// Gets the name of this control, if it has one.
+    ///
+    /// This is used to list the names of the fields in the form, for
+    /// instance, in [`FormToolData::field_names`].
+    fn name(&self) -> Option<&str> {
+        None
+    }
+
     /// Builds the control, returning the [`View`] that was built.
     fn render_control<FS: FormStyle>(
         fs: &FS,
@@ -122,7 +529,28 @@ pub trait ControlData<FD: FormToolData>: 'static {
     /// This is the data type returned by this control. Usually a [`String`].
     type ReturnType: Clone;
 
+    /// Gets the name of this control, if it has one.
+    ///
+    /// This is used to look up the state of the control by name, for
+    /// instance, in [`Form::field_errors`](crate::Form::field_errors).
+    fn name(&self) -> Option<&str> {
+        None
+    }
+
+    /// Turns a value of this control into text that can be shown to the
+    /// user outside of the control.
+    ///
+    /// This is used to list the control's old and new values in the form's
+    /// [`change_summary`](crate::FormBuilder::change_summary). By default,
+    /// this returns `None`, which leaves the control out of the summary.
+    fn value_text(&self, _value: &Self::ReturnType) -> Option<String> {
+        None
+    }
+
     /// Builds the control, returning the [`View`] that was built.
+    ///
+    /// `touched` becomes true once the user has changed the control's value
+    /// or the control has lost focus.
     fn render_control<FS: FormStyle>(
         fs: &FS,
         fd: RwSignal<FD>,
@@ -130,27 +558,105 @@ pub trait ControlData<FD: FormToolData>: 'static {
         value_getter: Signal<Self::ReturnType>,
         value_setter: SignalSetter<Self::ReturnType>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View;
+
+    /// Builds the control as read only text, for when the form is in
+    /// [`FormMode::Display`], returning the [`View`] that was built.
+    ///
+    /// By default, this renders the control as normal, but ignores any
+    /// changes to its value.
+    fn render_display<FS: FormStyle>(
+        fs: &FS,
+        fd: RwSignal<FD>,
+        control: Rc<ControlRenderData<FS, Self>>,
+        value_getter: Signal<Self::ReturnType>,
+    ) -> View {
+        Self::render_control(
+            fs,
+            fd,
+            control,
+            value_getter,
+            SignalSetter::map(|_| {}),
+            Signal::derive(|| ValidationState::Passed),
+            Signal::derive(|| false),
+        )
+    }
 }
 pub trait ValidatedControlData<FD: FormToolData>: ControlData<FD> {}
 
 /// The data needed to render a interactive control of type `C`.
 pub struct ControlRenderData<FS: FormStyle + ?Sized, C: ?Sized> {
     pub styles: Vec<FS::StylingAttributes>,
+    /// Styles that only apply while their signal is true.
+    pub conditional_styles: Vec<(Signal<bool>, FS::StylingAttributes)>,
+    /// A reference that the style should attach to the control's primary
+    /// input element.
+    pub node_ref: Option<NodeRef<AnyElement>>,
+    /// The tab index that the style should set on the control's
+    /// interactive elements.
+    pub tabindex: Option<i32>,
+    /// Whether the style should show the control's error message with the
+    /// control.
+    ///
+    /// This is false when the form shows its errors in a summary instead.
+    pub inline_errors: bool,
+    /// The native html validation attributes that the style should set on
+    /// the control's input, mirroring the control's validation.
+    ///
+    /// This is only set when the form uses
+    /// [`native_validation_fallback`](crate::FormBuilder::native_validation_fallback).
+    pub native_validation: Option<NativeValidation>,
     pub data: C,
 }
 
+impl<FS: FormStyle, C> ControlRenderData<FS, C> {
+    /// Creates a copy of this render data with different control data.
+    pub(crate) fn with_data<D>(&self, data: D) -> ControlRenderData<FS, D> {
+        ControlRenderData {
+            styles: self.styles.clone(),
+            conditional_styles: self.conditional_styles.clone(),
+            node_ref: self.node_ref,
+            tabindex: self.tabindex,
+            inline_errors: self.inline_errors,
+            native_validation: self.native_validation.clone(),
+            data,
+        }
+    }
+
+    /// Removes the native `min` and `max` validation attributes.
+    ///
+    /// These check the value in the control, so they are wrong for controls
+    /// that show a different value than the one that is stored.
+    pub(crate) fn without_native_range(mut self) -> Self {
+        if let Some(ref mut native_validation) = self.native_validation {
+            native_validation.min = None;
+            native_validation.max = None;
+        }
+        self
+    }
+}
+
 /// The data needed to render a read-only control of type `C`.
 pub struct VanityControlBuilder<FD: FormToolData, C: VanityControlData<FD>> {
     pub(crate) style_attributes: Vec<<FD::Style as FormStyle>::StylingAttributes>,
+    pub(crate) conditional_style_attributes:
+        Vec<(Signal<bool>, <FD::Style as FormStyle>::StylingAttributes)>,
     pub data: C,
     pub(crate) getter: Option<Rc<dyn FieldGetter<FD, String>>>,
+    /// Tracks the dependencies of the getter, if it is memoized.
+    pub(crate) getter_deps: Option<Rc<dyn DepsFn<FD>>>,
     pub(crate) show_when: Option<Box<dyn ShowWhenFn<FD, FD::Context>>>,
+    /// The name of the form's condition to use as the `show_when`.
+    pub(crate) show_when_named: Option<String>,
+    pub(crate) tabindex: Option<i32>,
 }
 
 pub(crate) struct BuiltVanityControlData<FD: FormToolData, C: VanityControlData<FD>> {
     pub(crate) render_data: ControlRenderData<FD::Style, C>,
     pub(crate) getter: Option<Rc<dyn FieldGetter<FD, String>>>,
+    /// Tracks the dependencies of the getter, if it is memoized.
+    pub(crate) getter_deps: Option<Rc<dyn DepsFn<FD>>>,
     pub(crate) show_when: Option<Box<dyn ShowWhenFn<FD, FD::Context>>>,
 }
 
@@ -160,8 +666,12 @@ impl<FD: FormToolData, C: VanityControlData<FD>> VanityControlBuilder<FD, C> {
         VanityControlBuilder {
             data,
             style_attributes: Vec::new(),
+            conditional_style_attributes: Vec::new(),
             getter: None,
+            getter_deps: None,
             show_when: None,
+            show_when_named: None,
+            tabindex: None,
         }
     }
 
@@ -171,8 +681,14 @@ impl<FD: FormToolData, C: VanityControlData<FD>> VanityControlBuilder<FD, C> {
             render_data: ControlRenderData {
                 data: self.data,
                 styles: self.style_attributes,
+                conditional_styles: self.conditional_style_attributes,
+                node_ref: None,
+                tabindex: self.tabindex,
+                inline_errors: true,
+                native_validation: None,
             },
             getter: self.getter,
+            getter_deps: self.getter_deps,
             show_when: self.show_when,
         }
     }
@@ -188,11 +704,43 @@ impl<FD: FormToolData, C: VanityControlData<FD>> VanityControlBuilder<FD, C> {
         self
     }
 
+    /// Uses the form's condition with the given name to decide when to
+    /// render the control.
+    ///
+    /// The condition must be defined with
+    /// [`define_condition`](crate::FormBuilder::define_condition) before
+    /// this control is added to the form.
+    pub fn show_when_named(mut self, name: impl ToString) -> Self {
+        self.show_when_named = Some(name.to_string());
+        self
+    }
+
     /// Adds a styling attribute to this control.
     pub fn style(mut self, attribute: <FD::Style as FormStyle>::StylingAttributes) -> Self {
         self.style_attributes.push(attribute);
         self
     }
+
+    /// Adds a styling attribute to this control that only applies while
+    /// `when` is true.
+    pub fn style_when(
+        mut self,
+        when: impl Into<Signal<bool>>,
+        attribute: <FD::Style as FormStyle>::StylingAttributes,
+    ) -> Self {
+        self.conditional_style_attributes
+            .push((when.into(), attribute));
+        self
+    }
+
+    /// Sets the tab index of the control.
+    ///
+    /// This allows a custom tab order, like when the visual layout of the
+    /// form differs from the order of the controls.
+    pub fn tabindex(mut self, tabindex: i32) -> Self {
+        self.tabindex = Some(tabindex);
+        self
+    }
 }
 
 impl<FD: FormToolData, C: GetterVanityControlData<FD>> VanityControlBuilder<FD, C> {
@@ -203,6 +751,32 @@ impl<FD: FormToolData, C: GetterVanityControlData<FD>> VanityControlBuilder<FD,
     /// Setting this getter field is NOT required for vanity controls like this one.
     pub fn getter(mut self, getter: impl FieldGetter<FD, String>) -> Self {
         self.getter = Some(Rc::new(getter));
+        self.getter_deps = None;
+        self
+    }
+
+    /// Sets a memoized getter function.
+    ///
+    /// Unlike [`getter`](Self::getter), the getter is only run again when
+    /// the value returned by `deps` changes, rather than on every change to
+    /// the form data. This is useful when the getter is expensive, like
+    /// formatting a summary of the form.
+    ///
+    /// `deps` should return all the parts of the form data that the getter
+    /// uses, otherwise the value may be stale.
+    pub fn getter_memo<D: PartialEq + 'static>(
+        mut self,
+        deps: impl Fn(&FD) -> D + 'static,
+        getter: impl FieldGetter<FD, String>,
+    ) -> Self {
+        let deps = Rc::new(deps);
+        let getter_deps = move |fd: RwSignal<FD>| {
+            let deps = deps.clone();
+            let deps = create_memo(move |_| fd.with(|fd| deps(fd)));
+            Signal::derive(move || deps.track())
+        };
+        self.getter = Some(Rc::new(getter));
+        self.getter_deps = Some(Rc::new(getter_deps));
         self
     }
 }
@@ -231,15 +805,36 @@ impl Display for ControlBuildError {
     }
 }
 
+/// The named checks of a control.
+pub(crate) type Checklist<FD> = Vec<(String, Box<dyn CheckFn<FD>>)>;
+
+/// Creates a validation function from the control's data, getter and
+/// unparse function, once those are known when the control is built.
+pub(crate) type DeferredValidation<FD, C, FDT> = Box<
+    dyn FnOnce(
+        &C,
+        Rc<dyn FieldGetter<FD, FDT>>,
+        Rc<dyn UnparseFn<<C as ControlData<FD>>::ReturnType, FDT>>,
+    ) -> Rc<dyn ValidationFn<FD>>,
+>;
+
 /// The data returned from a control's build function.
 pub(crate) struct BuiltControlData<FD: FormToolData, C: ControlData<FD>, FDT> {
     pub(crate) render_data: ControlRenderData<FD::Style, C>,
     pub(crate) getter: Rc<dyn FieldGetter<FD, FDT>>,
     pub(crate) setter: Rc<dyn FieldSetter<FD, FDT>>,
-    pub(crate) parse_fn: Box<dyn ParseFn<C::ReturnType, FDT>>,
+    pub(crate) parse_fn: Rc<dyn ParseFn<C::ReturnType, FDT>>,
     pub(crate) unparse_fn: Box<dyn UnparseFn<C::ReturnType, FDT>>,
     pub(crate) validation_fn: Option<Rc<dyn ValidationFn<FD>>>,
+    pub(crate) checklist: Rc<Checklist<FD>>,
     pub(crate) show_when: Option<Rc<dyn ShowWhenFn<FD, FD::Context>>>,
+    pub(crate) exclude_when: Option<Rc<dyn ExcludeWhenFn<FD, FDT>>>,
+    pub(crate) defer_unparse: bool,
+    pub(crate) keep_invalid_input: bool,
+    pub(crate) custom_parse_msg: bool,
+    pub(crate) revalidate_on: Option<Signal<()>>,
+    pub(crate) details_help: Option<(String, String)>,
+    pub(crate) native_validation: Option<NativeValidation>,
 }
 
 /// A builder for a interactive control.
@@ -249,8 +844,36 @@ pub struct ControlBuilder<FD: FormToolData, C: ControlData<FD>, FDT> {
     pub(crate) parse_fn: Option<Box<dyn ParseFn<C::ReturnType, FDT>>>,
     pub(crate) unparse_fn: Option<Box<dyn UnparseFn<C::ReturnType, FDT>>>,
     pub(crate) validation_fn: Option<Rc<dyn ValidationFn<FD>>>,
+    /// Validations that run after the `validation_fn`, which are created
+    /// when the control is built.
+    pub(crate) deferred_validations: Vec<DeferredValidation<FD, C, FDT>>,
+    pub(crate) checklist: Checklist<FD>,
     pub(crate) style_attributes: Vec<<FD::Style as FormStyle>::StylingAttributes>,
+    pub(crate) conditional_style_attributes:
+        Vec<(Signal<bool>, <FD::Style as FormStyle>::StylingAttributes)>,
     pub(crate) show_when: Option<Rc<dyn ShowWhenFn<FD, FD::Context>>>,
+    /// The name of the form's condition to use as the `show_when`.
+    pub(crate) show_when_named: Option<String>,
+    pub(crate) exclude_when: Option<Rc<dyn ExcludeWhenFn<FD, FDT>>>,
+    pub(crate) normalize_fn: Option<Rc<dyn NormalizeFn<C::ReturnType>>>,
+    /// Checks or clamps the parsed value to the control's numeric range.
+    pub(crate) range_fn: Option<Rc<dyn ParseFn<FDT, FDT>>>,
+    /// Whether to wait until the control loses focus to show the unparsed
+    /// value, if it parses to the same value as what the user typed.
+    pub(crate) defer_unparse: bool,
+    /// Whether to keep showing what the user typed when it fails to parse.
+    pub(crate) keep_invalid_input: bool,
+    /// Whether the parse function has a custom error message, which takes
+    /// priority over the form's default parse error.
+    pub(crate) custom_parse_msg: bool,
+    /// A signal that reruns the control's validation when it changes.
+    pub(crate) revalidate_on: Option<Signal<()>>,
+    /// The summary and content of the expandable help under the control.
+    pub(crate) details_help: Option<(String, String)>,
+    /// The native html validation attributes that mirror the validation.
+    pub(crate) native_validation: Option<NativeValidation>,
+    pub(crate) node_ref: Option<NodeRef<AnyElement>>,
+    pub(crate) tabindex: Option<i32>,
     pub data: C,
 }
 
@@ -264,15 +887,71 @@ impl<FD: FormToolData, C: ControlData<FD>, FDT> ControlBuilder<FD, C, FDT> {
             parse_fn: None,
             unparse_fn: None,
             validation_fn: None,
+            deferred_validations: Vec::new(),
+            checklist: Vec::new(),
             style_attributes: Vec::new(),
+            conditional_style_attributes: Vec::new(),
             show_when: None,
+            show_when_named: None,
+            exclude_when: None,
+            normalize_fn: None,
+            range_fn: None,
+            defer_unparse: false,
+            keep_invalid_input: false,
+            custom_parse_msg: false,
+            revalidate_on: None,
+            details_help: None,
+            native_validation: None,
+            node_ref: None,
+            tabindex: None,
         }
     }
 
+    /// Adds a function that checks or clamps the parsed value, running
+    /// after any previously added range functions.
+    ///
+    /// Since this can change the value, the changed value is only shown
+    /// once the control loses focus, so it doesn't replace what the user is
+    /// still typing.
+    pub(crate) fn add_range_fn(&mut self, range_fn: impl ParseFn<FDT, FDT>)
+    where
+        FDT: 'static,
+    {
+        self.defer_unparse = true;
+        self.range_fn = Some(match self.range_fn.take() {
+            Some(prev) => Rc::new(move |value| prev(value).and_then(&range_fn)),
+            None => Rc::new(range_fn),
+        });
+    }
+
+    /// Adds a range function that snaps the parsed value to the nearest of
+    /// the `allowed` values.
+    ///
+    /// This is for discrete, but irregular, ranges like shoe sizes
+    /// (6, 6.5, 7, 8, 10), where a step is not enough. If `allowed` is
+    /// empty, values are left as is.
+    pub(crate) fn add_snap_fn(&mut self, allowed: Vec<FDT>)
+    where
+        FDT: Clone + Into<f64> + 'static,
+    {
+        self.add_range_fn(move |value: FDT| {
+            let target: f64 = value.clone().into();
+            let distance = |allowed: &FDT| (allowed.clone().into() - target).abs();
+            let nearest = allowed
+                .iter()
+                .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+                .cloned();
+            Ok(nearest.unwrap_or(value))
+        });
+    }
+
     /// Builds the builder into the data needed to render the control.
     ///
     /// This fails if a required field was not specified.
-    pub(crate) fn build(self) -> Result<BuiltControlData<FD, C, FDT>, ControlBuildError> {
+    pub(crate) fn build(self) -> Result<BuiltControlData<FD, C, FDT>, ControlBuildError>
+    where
+        FDT: 'static,
+    {
         let getter = match self.getter {
             Some(getter) => getter,
             None => return Err(ControlBuildError::MissingGetter),
@@ -289,18 +968,82 @@ impl<FD: FormToolData, C: ControlData<FD>, FDT> ControlBuilder<FD, C, FDT> {
             Some(unparse_fn) => unparse_fn,
             None => return Err(ControlBuildError::MissingUnParseFn),
         };
+        // keep the parsed value in the control's range
+        let parse_fn: Box<dyn ParseFn<C::ReturnType, FDT>> = match self.range_fn {
+            Some(range_fn) => Box::new(move |value| parse_fn(value).and_then(|v| range_fn(v))),
+            None => parse_fn,
+        };
+        // normalize the value before parsing, and after unparsing
+        let (parse_fn, unparse_fn) = match self.normalize_fn {
+            Some(normalize_fn) => {
+                let normalize_fn_clone = normalize_fn.clone();
+                let parse_fn: Rc<dyn ParseFn<C::ReturnType, FDT>> =
+                    Rc::new(move |value| parse_fn(normalize_fn_clone(value)));
+                let unparse_fn: Box<dyn UnparseFn<C::ReturnType, FDT>> =
+                    Box::new(move |field| normalize_fn(unparse_fn(field)));
+                (parse_fn, unparse_fn)
+            }
+            None => (Rc::from(parse_fn), unparse_fn),
+        };
+
+        // the deferred validations run after the user's validation function
+        let mut validation_fns: Vec<Rc<dyn ValidationFn<FD>>> =
+            self.validation_fn.into_iter().collect();
+        let unparse_fn = if self.deferred_validations.is_empty() {
+            unparse_fn
+        } else {
+            let unparse_fn: Rc<dyn UnparseFn<C::ReturnType, FDT>> = Rc::from(unparse_fn);
+            validation_fns.extend(
+                self.deferred_validations
+                    .into_iter()
+                    .map(|deferred| deferred(&self.data, getter.clone(), unparse_fn.clone())),
+            );
+            Box::new(move |field| unparse_fn(field))
+        };
+
+        // the checks also need to pass for the control to be valid
+        let checklist = Rc::new(self.checklist);
+        let validation_fn = match (validation_fns.len(), checklist.is_empty()) {
+            (0 | 1, true) => validation_fns.pop(),
+            _ => {
+                let checklist = checklist.clone();
+                let validation_fn = move |fd: &FD| {
+                    for validation_fn in validation_fns.iter() {
+                        validation_fn(fd)?;
+                    }
+                    match checklist.iter().find(|(_, check)| !check(fd)) {
+                        Some((name, _)) => Err(name.clone()),
+                        None => Ok(()),
+                    }
+                };
+                Some(Rc::new(validation_fn) as Rc<dyn ValidationFn<FD>>)
+            }
+        };
 
         Ok(BuiltControlData {
             render_data: ControlRenderData {
                 data: self.data,
                 styles: self.style_attributes,
+                conditional_styles: self.conditional_style_attributes,
+                node_ref: self.node_ref,
+                tabindex: self.tabindex,
+                inline_errors: true,
+                native_validation: None,
             },
             getter,
             setter,
             parse_fn,
             unparse_fn,
-            validation_fn: self.validation_fn,
+            validation_fn,
+            checklist,
             show_when: self.show_when,
+            exclude_when: self.exclude_when,
+            defer_unparse: self.defer_unparse,
+            keep_invalid_input: self.keep_invalid_input,
+            custom_parse_msg: self.custom_parse_msg,
+            revalidate_on: self.revalidate_on,
+            details_help: self.details_help,
+            native_validation: self.native_validation,
         })
     }
 
@@ -315,6 +1058,75 @@ impl<FD: FormToolData, C: ControlData<FD>, FDT> ControlBuilder<FD, C, FDT> {
         self
     }
 
+    /// Uses the form's condition with the given name to decide when to
+    /// render the control.
+    ///
+    /// Validations for components that are not shown DO NOT run.
+    ///
+    /// The condition must be defined with
+    /// [`define_condition`](crate::FormBuilder::define_condition) before
+    /// this control is added to the form.
+    pub fn show_when_named(mut self, name: impl ToString) -> Self {
+        self.show_when_named = Some(name.to_string());
+        self
+    }
+
+    /// Binds the control's primary input element to the given [`NodeRef`].
+    ///
+    /// This allows running custom code on the element, like focusing it or
+    /// integrating a js library, without losing the validation of the
+    /// control. For controls without a single input element, like radio
+    /// buttons, the reference is bound to the element containing the
+    /// inputs.
+    pub fn node_ref(mut self, node_ref: NodeRef<AnyElement>) -> Self {
+        self.node_ref = Some(node_ref);
+        self
+    }
+
+    /// Sets the tab index of the control.
+    ///
+    /// This allows a custom tab order, like when the visual layout of the
+    /// form differs from the order of the controls.
+    pub fn tabindex(mut self, tabindex: i32) -> Self {
+        self.tabindex = Some(tabindex);
+        self
+    }
+
+    /// Reruns the control's validation whenever `trigger` changes, even if
+    /// the value has not.
+    ///
+    /// This is for validations that depend on something other than the
+    /// form data, like the time for "must be in the future". A clock signal
+    /// can be made by updating a signal with leptos' `set_interval`. The
+    /// validation only reruns once the control has been touched, so errors
+    /// aren't shown before the user has entered anything.
+    pub fn revalidate_on(mut self, trigger: impl Into<Signal<()>>) -> Self {
+        self.revalidate_on = Some(trigger.into());
+        self
+    }
+
+    /// Adds expandable help beneath the control.
+    ///
+    /// The `summary` is always shown, and the `content` is shown once the
+    /// user expands it, like the html `<details>` element. It is collapsed
+    /// by default. This is suited to paragraph-length explanations that
+    /// would otherwise clutter the form.
+    pub fn details_help(mut self, summary: impl ToString, content: impl ToString) -> Self {
+        self.details_help = Some((summary.to_string(), content.to_string()));
+        self
+    }
+
+    /// Keeps what the user entered in the control when it fails to parse.
+    ///
+    /// Normally, the control's value is only updated with values that parse,
+    /// so the invalid text can be replaced with the last valid value when the
+    /// control is rendered again. With this, the invalid text stays in the
+    /// control until the user fixes it.
+    pub fn keep_invalid_input(mut self) -> Self {
+        self.keep_invalid_input = true;
+        self
+    }
+
     /// Sets the getter function.
     ///
     /// This function should get the field from the form data
@@ -349,6 +1161,7 @@ impl<FD: FormToolData, C: ControlData<FD>, FDT> ControlBuilder<FD, C, FDT> {
     ) -> Self {
         self.parse_fn = Some(Box::new(parse_fn));
         self.unparse_fn = Some(Box::new(unparse_fn));
+        self.custom_parse_msg = true;
         self
     }
 
@@ -357,6 +1170,42 @@ impl<FD: FormToolData, C: ControlData<FD>, FDT> ControlBuilder<FD, C, FDT> {
         self.style_attributes.push(attribute);
         self
     }
+
+    /// Adds a styling attribute to this control that only applies while
+    /// `when` is true.
+    pub fn style_when(
+        mut self,
+        when: impl Into<Signal<bool>>,
+        attribute: <FD::Style as FormStyle>::StylingAttributes,
+    ) -> Self {
+        self.conditional_style_attributes
+            .push((when.into(), attribute));
+        self
+    }
+}
+
+impl<FD, C, FDT> ControlBuilder<FD, C, FDT>
+where
+    FD: FormToolData,
+    C: ControlData<FD>,
+    FDT: Default + 'static,
+{
+    /// Sets the function to decide when this field should be excluded from
+    /// the submitted data.
+    ///
+    /// When `when` returns true, the field is reset to its default value
+    /// before the form data is sent. This is usually paired with
+    /// [`show_when`](Self::show_when) so that stale values in hidden
+    /// fields are not submitted.
+    ///
+    /// This only applies to forms built with
+    /// [`get_form`](crate::FormToolData::get_form), as the other form
+    /// types send the values of the html elements, and hidden elements
+    /// are not rendered.
+    pub fn exclude_when(mut self, when: impl Fn(&FD) -> bool + 'static) -> Self {
+        self.exclude_when = Some(Rc::new(move |fd| when(fd).then(FDT::default)));
+        self
+    }
 }
 
 impl<FD, C, FDT> ControlBuilder<FD, C, FDT>
@@ -380,6 +1229,7 @@ where
         self.unparse_fn = Some(Box::new(|field| {
             <C as ControlData<FD>>::ReturnType::from(field)
         }));
+        self.custom_parse_msg = false;
         self
     }
 }
@@ -404,6 +1254,30 @@ where
         self.unparse_fn = Some(Box::new(|field| {
             <C as ControlData<FD>>::ReturnType::from(field)
         }));
+        self.custom_parse_msg = true;
+        self
+    }
+
+    /// Sets the parse functions to use the [`TryFrom`] and [`From`] traits
+    /// for parsing and unparsing respectively, with an error message built
+    /// from the value that failed to parse.
+    ///
+    /// Ex. `.parse_from_fmt(|v| format!("'{}' is not a valid color", v))`
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// types in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn parse_from_fmt(
+        mut self,
+        fmt: impl Fn(&<C as ControlData<FD>>::ReturnType) -> String + 'static,
+    ) -> Self {
+        self.parse_fn = Some(Box::new(move |control_return_value| {
+            FDT::try_from(control_return_value.clone()).map_err(|_| fmt(&control_return_value))
+        }));
+        self.unparse_fn = Some(Box::new(|field| {
+            <C as ControlData<FD>>::ReturnType::from(field)
+        }));
+        self.custom_parse_msg = true;
         self
     }
 }
@@ -429,6 +1303,7 @@ where
                 .map_err(|e| e.to_string())
         }));
         self.unparse_fn = Some(Box::new(|field| field.to_string()));
+        self.custom_parse_msg = false;
         self
     }
 
@@ -448,6 +1323,7 @@ where
                 .map_err(|e| e.to_string())
         }));
         self.unparse_fn = Some(Box::new(|field| field.to_string()));
+        self.custom_parse_msg = false;
         self
     }
 
@@ -466,6 +1342,25 @@ where
                 .map_err(|_| msg.to_string())
         }));
         self.unparse_fn = Some(Box::new(|field| field.to_string()));
+        self.custom_parse_msg = true;
+        self
+    }
+
+    /// Sets the parse functions to trim the string before parsing, like
+    /// [`parse_trimmed`](Self::parse_trimmed)(), but doesn't replace what
+    /// the user typed with the trimmed value until the control loses focus.
+    ///
+    /// With [`UpdateEvent::OnInput`], [`parse_trimmed`](Self::parse_trimmed)
+    /// removes a trailing space as soon as it is typed, which makes typing
+    /// spaces between words impossible. This avoids that, while still
+    /// storing the trimmed value.
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// types in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn parse_trimmed_lazy(mut self) -> Self {
+        self = self.parse_trimmed();
+        self.defer_unparse = true;
         self
     }
 
@@ -486,6 +1381,71 @@ where
                 .map_err(|_| msg.to_string())
         }));
         self.unparse_fn = Some(Box::new(|field| field.to_string()));
+        self.custom_parse_msg = true;
+        self
+    }
+}
+
+impl<FD, C, FDT> ControlBuilder<FD, C, FDT>
+where
+    FD: FormToolData,
+    C: ControlData<FD, ReturnType = String>,
+    FDT: Primitive,
+{
+    /// Sets the parse functions for a scalar type, using the [`Primitive`]
+    /// trait.
+    ///
+    /// This works for [`bool`], [`char`], and the integer and float types
+    /// without needing any conversion traits. Numbers are trimmed before
+    /// parsing, and a [`bool`] accepts values like `"true"`, `"on"` and
+    /// `"yes"`, which makes it useful for select and radio button
+    /// controls.
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// types in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn parse_primitive(mut self) -> Self {
+        self.parse_fn = Some(Box::new(|control_return_value: String| {
+            FDT::parse_primitive(&control_return_value)
+        }));
+        self.unparse_fn = Some(Box::new(|field: FDT| field.unparse_primitive()));
+        self.custom_parse_msg = false;
+        self
+    }
+
+    /// Sets the parse functions for a number that is shown to the user
+    /// divided by the `scale`, using the [`Primitive`] trait.
+    ///
+    /// The stored value is the value the user types times the `scale`.
+    pub(crate) fn parse_scaled(&mut self, scale: ScaleFactor) {
+        self.parse_fn = Some(Box::new(move |control_return_value: String| {
+            f64::parse_primitive(&control_return_value)?;
+            FDT::parse_primitive(&scale.stored_value(&control_return_value))
+        }));
+        self.unparse_fn = Some(Box::new(move |field: FDT| {
+            scale.shown_value(&field.unparse_primitive())
+        }));
+        self.custom_parse_msg = false;
+    }
+}
+
+impl<FD, C, FDT> ControlBuilder<FD, C, FDT>
+where
+    FD: FormToolData,
+    C: ControlData<FD, ReturnType = String>,
+{
+    /// Sets how whitespace in the value is normalized.
+    ///
+    /// The whitespace is normalized before the value is parsed, so this
+    /// works with any of the parse functions. The control also shows the
+    /// normalized value once it is set.
+    ///
+    /// Defaults to [`WhitespaceMode::None`].
+    pub fn whitespace(mut self, mode: WhitespaceMode) -> Self {
+        self.normalize_fn = match mode {
+            WhitespaceMode::None => None,
+            mode => Some(Rc::new(move |value: String| mode.apply(&value))),
+        };
         self
     }
 }
@@ -536,6 +1496,71 @@ where
     }
 }
 
+impl<FD, C, FDT> ControlBuilder<FD, C, Option<FDT>>
+where
+    FD: FormToolData,
+    C: ControlData<FD, ReturnType = String>,
+    FDT: FromStr + ToString,
+    <FDT as FromStr>::Err: ToString,
+{
+    /// Sets the parse functions to use the [`FromStr`] [`ToString`] and traits
+    /// on on optional value for parsing and unparsing respectively.
+    /// An empty string will pass the `None` varient, otherwise the value is
+    /// parsed, and a parse failure is reported as an error.
+    ///
+    /// This differs from [`parse_optional`](Self::parse_optional)() in that
+    /// invalid input is not silently turned into `None`.
+    ///
+    /// To trim the string before parsing, see
+    /// [`parse_optional_strict_trimmed`](Self::parse_optional_strict_trimmed)().
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// types in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn parse_optional_strict(mut self) -> Self {
+        self.parse_fn = Some(Box::new(|control_return_value| {
+            if control_return_value.is_empty() {
+                return Ok(None);
+            }
+            control_return_value
+                .parse::<FDT>()
+                .map(Some)
+                .map_err(|e| e.to_string())
+        }));
+        self.unparse_fn = Some(Box::new(|field| {
+            field.map(|v| v.to_string()).unwrap_or_default()
+        }));
+        self.custom_parse_msg = false;
+        self
+    }
+
+    /// Sets the parse functions to use the [`FromStr`] [`ToString`] and traits
+    /// on on optional value for parsing and unparsing respectively, similar
+    /// to [`parse_optional_strict`](Self::parse_optional_strict)().
+    /// However, this method trims the string before parsing.
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// types in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn parse_optional_strict_trimmed(mut self) -> Self {
+        self.parse_fn = Some(Box::new(|control_return_value| {
+            let control_return_value = control_return_value.trim();
+            if control_return_value.is_empty() {
+                return Ok(None);
+            }
+            control_return_value
+                .parse::<FDT>()
+                .map(Some)
+                .map_err(|e| e.to_string())
+        }));
+        self.unparse_fn = Some(Box::new(|field| {
+            field.map(|v| v.to_string()).unwrap_or_default()
+        }));
+        self.custom_parse_msg = false;
+        self
+    }
+}
+
 impl<FD, C, FDT> ControlBuilder<FD, C, FDT>
 where
     FD: FormToolData,
@@ -598,4 +1623,31 @@ impl<FD: FormToolData, C: ValidatedControlData<FD>, FDT> ControlBuilder<FD, C, F
         self.validation_fn = Some(Rc::new(validation_fn));
         self
     }
+
+    /// Sets the validation function for this control from a
+    /// [`ValidationBuilder`].
+    ///
+    /// This is like [`validation_fn`](Self::validation_fn), but also keeps
+    /// the builder's [`NativeValidation`] attributes, so that they can be
+    /// set on the control with
+    /// [`native_validation_fallback`](crate::FormBuilder::native_validation_fallback).
+    pub fn validation<T: ?Sized + 'static>(mut self, builder: ValidationBuilder<FD, T>) -> Self {
+        self.native_validation = Some(builder.native_validation());
+        self.validation_fn = Some(Rc::new(builder.build()));
+        self
+    }
+
+    /// Adds a named check to this control's checklist.
+    ///
+    /// Each check is a rule that the field must satisfy, such as a password
+    /// being at least 8 characters long. Whether each check currently
+    /// passes can be read with [`Form::checklist`](crate::Form::checklist)()
+    /// to render a live checklist for the user.
+    ///
+    /// The checks are run after the validation function, and the name of
+    /// the first failing check is used as the validation error message.
+    pub fn check(mut self, name: impl ToString, check: impl Fn(&FD) -> bool + 'static) -> Self {
+        self.checklist.push((name.to_string(), Box::new(check)));
+        self
+    }
 }
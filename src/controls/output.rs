@@ -8,7 +8,12 @@ use std::rc::Rc;
 
 /// Data used for the output control.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct OutputData;
+pub struct OutputData {
+    /// Whether line breaks in the text are shown.
+    pub multiline: bool,
+    /// Whether each line of the text is shown as an item in a list.
+    pub list: bool,
+}
 
 impl<FD: FormToolData> VanityControlData<FD> for OutputData {
     fn render_control<FS: FormStyle>(
@@ -43,3 +48,23 @@ impl<FD: FormToolData> FormBuilder<FD> {
         self.new_vanity_cx(builder)
     }
 }
+
+impl<FD: FormToolData> VanityControlBuilder<FD, OutputData> {
+    /// Shows the line breaks in the output's text, rather than collapsing
+    /// them into spaces.
+    pub fn multiline(mut self) -> Self {
+        self.data.multiline = true;
+        self
+    }
+
+    /// Sets the getter to a function that returns a list of lines, which are
+    /// shown as a bulleted list.
+    ///
+    /// This is useful for showing lists derived from the form data, like a
+    /// summary of the selected items.
+    pub fn lines_getter(self, getter: impl Fn(&FD) -> Vec<String> + 'static) -> Self {
+        let mut builder = self.getter(move |fd| getter(fd).join("\n"));
+        builder.data.list = true;
+        builder
+    }
+}
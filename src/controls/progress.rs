@@ -0,0 +1,84 @@
+use super::{BuilderCxFn, BuilderFn, ControlRenderData, VanityControlBuilder, VanityControlData};
+use crate::{
+    form::{completion, FormToolData},
+    form_builder::FormBuilder,
+    styles::FormStyle,
+};
+use leptos::{MaybeSignal, RwSignal, Signal, View};
+use std::rc::Rc;
+
+/// Data used for the progress control.
+#[derive(Debug, Clone, Default)]
+pub struct ProgressData {
+    pub label: Option<MaybeSignal<String>>,
+    /// How complete the form is, from 0.0 to 1.0.
+    ///
+    /// This is filled in when the form is rendered.
+    pub completion: Option<Signal<f32>>,
+}
+
+impl<FD: FormToolData> VanityControlData<FD> for ProgressData {
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: Rc<ControlRenderData<FS, Self>>,
+        _value_getter: Option<Signal<String>>,
+    ) -> View {
+        let completion = control
+            .data
+            .completion
+            .unwrap_or_else(|| Signal::derive(|| 0.0));
+        fs.progress(control, completion)
+    }
+}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a progress bar and adds it to the form.
+    ///
+    /// This shows the fraction of the validated controls that currently
+    /// pass their validation. See [`Form::completion`](crate::Form::completion).
+    pub fn progress(
+        mut self,
+        builder: impl BuilderFn<VanityControlBuilder<FD, ProgressData>>,
+    ) -> Self {
+        let control = builder(VanityControlBuilder::new(ProgressData::default()));
+        self.add_progress(control);
+        self
+    }
+
+    /// Builds a progress bar using the form's context and adds it to the
+    /// form.
+    pub fn progress_cx(
+        mut self,
+        builder: impl BuilderCxFn<VanityControlBuilder<FD, ProgressData>, FD::Context>,
+    ) -> Self {
+        let control = builder(
+            VanityControlBuilder::new(ProgressData::default()),
+            self.cx.clone(),
+        );
+        self.add_progress(control);
+        self
+    }
+
+    fn add_progress(&mut self, control: VanityControlBuilder<FD, ProgressData>) {
+        self.add_vanity_with_state(control, |data, render_state| {
+            data.completion = Some(completion(render_state.control_states));
+        });
+    }
+}
+
+impl<FD: FormToolData> VanityControlBuilder<FD, ProgressData> {
+    /// Sets the label for the progress bar.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.label = Some(MaybeSignal::Static(label.to_string()));
+        self
+    }
+
+    /// Sets the label for the progress bar to a signal.
+    ///
+    /// This is useful for labels that change, like localized labels.
+    pub fn labeled_signal(mut self, label: Signal<String>) -> Self {
+        self.data.label = Some(MaybeSignal::Dynamic(label));
+        self
+    }
+}
@@ -1,25 +1,64 @@
 use super::{
-    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidatedControlData,
-    ValidationState,
+    display::DisplayData, BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData,
+    FormOptions, ValidatedControlData, ValidationState,
 };
 use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
-use leptos::{RwSignal, Signal, SignalSetter, View};
-use std::rc::Rc;
+use leptos::{MaybeSignal, RwSignal, Signal, SignalGet, SignalSetter, View};
+use std::{collections::BTreeMap, rc::Rc};
+
+/// How the options of a radio buttons control are displayed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum RadioStyle {
+    /// Each option is a radio button with a label next to it.
+    #[default]
+    Inline,
+    /// Each option is a box that can be clicked anywhere to select it, and
+    /// is highlighted while selected.
+    ///
+    /// This gives a larger target, which is easier to use on touch screens.
+    Card,
+}
 
 /// Data used for the radio buttons control.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct RadioButtonsData {
     pub name: String,
-    pub label: Option<String>,
+    pub label: Option<MaybeSignal<String>>,
     /// The options for the select.
     ///
     /// The first value is the string to display, the second is the value.
     pub options: Vec<(String, String)>,
+    /// The descriptions for the options.
+    ///
+    /// The key is the option's value, the value is the description.
+    /// Options without an entry have no description.
+    pub descriptions: BTreeMap<String, String>,
+    /// The display text for the "other" option, if there is one.
+    ///
+    /// Choosing the "other" option shows a text input, and the text typed
+    /// in it becomes the control's value.
+    pub other_option: Option<String>,
+    /// How the options are displayed.
+    pub style: RadioStyle,
 }
 
 impl<FD: FormToolData> ControlData<FD> for RadioButtonsData {
     type ReturnType = String;
 
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn value_text(&self, value: &Self::ReturnType) -> Option<String> {
+        // show the display string of the option
+        let display = self
+            .options
+            .iter()
+            .find(|(_, v)| v == value)
+            .map(|(display, _)| display.clone());
+        Some(display.unwrap_or_else(|| value.clone()))
+    }
+
     fn render_control<FS: FormStyle>(
         fs: &FS,
         _fd: RwSignal<FD>,
@@ -27,8 +66,40 @@ impl<FD: FormToolData> ControlData<FD> for RadioButtonsData {
         value_getter: Signal<Self::ReturnType>,
         value_setter: SignalSetter<Self::ReturnType>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
+    ) -> View {
+        fs.radio_buttons(
+            control,
+            value_getter,
+            value_setter,
+            validation_state,
+            touched,
+        )
+    }
+
+    fn render_display<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: Rc<ControlRenderData<FS, Self>>,
+        value_getter: Signal<Self::ReturnType>,
     ) -> View {
-        fs.radio_buttons(control, value_getter, value_setter, validation_state)
+        // show the display string of the selected option
+        let options = control.data.options.clone();
+        let value = move || {
+            let value = value_getter.get();
+            options
+                .iter()
+                .find(|(_, v)| *v == value)
+                .map(|(display, _)| display.clone())
+                .unwrap_or(value)
+        };
+        DisplayData::render(
+            fs,
+            &control,
+            &control.data.name,
+            control.data.label.as_ref(),
+            value,
+        )
     }
 }
 impl<FD: FormToolData> ValidatedControlData<FD> for RadioButtonsData {}
@@ -65,7 +136,15 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, RadioButtonsData, FDT> {
 
     /// Sets the label for the radio button group.
     pub fn labeled(mut self, label: impl ToString) -> Self {
-        self.data.label = Some(label.to_string());
+        self.data.label = Some(MaybeSignal::Static(label.to_string()));
+        self
+    }
+
+    /// Sets the label for the radio buttons to a signal.
+    ///
+    /// This is useful for labels that change, like localized labels.
+    pub fn labeled_signal(mut self, label: Signal<String>) -> Self {
+        self.data.label = Some(MaybeSignal::Dynamic(label));
         self
     }
 
@@ -86,6 +165,23 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, RadioButtonsData, FDT> {
         self
     }
 
+    /// Adds the option to the radio button group, specifying a different
+    /// value than what is displayed, along with a description that is
+    /// shown with the option.
+    pub fn with_option_described(
+        mut self,
+        display: impl ToString,
+        value: impl ToString,
+        description: impl ToString,
+    ) -> Self {
+        let value = value.to_string();
+        self.data
+            .descriptions
+            .insert(value.clone(), description.to_string());
+        self.data.options.push((display.to_string(), value));
+        self
+    }
+
     /// Adds all the options in the provided iterator to the radio button
     /// group.
     pub fn with_options(mut self, options: impl Iterator<Item = impl ToString>) -> Self {
@@ -110,4 +206,83 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, RadioButtonsData, FDT> {
         }
         self
     }
+
+    /// Adds an "Other" option as the last option of the radio button group.
+    ///
+    /// Choosing it shows a text input for a custom value, and whatever is
+    /// typed there becomes the control's value. Validation applies to the
+    /// typed value.
+    pub fn with_other_option(self) -> Self {
+        self.with_other_option_displayed("Other")
+    }
+
+    /// Adds an "other" option as the last option of the radio button group,
+    /// but sets the display string to the given value.
+    ///
+    /// Choosing it shows a text input for a custom value, and whatever is
+    /// typed there becomes the control's value. Validation applies to the
+    /// typed value.
+    pub fn with_other_option_displayed(mut self, display: impl ToString) -> Self {
+        self.data.other_option = Some(display.to_string());
+        self
+    }
+
+    /// Sets how the options are displayed.
+    ///
+    /// See [`RadioStyle`].
+    pub fn radio_style(mut self, style: RadioStyle) -> Self {
+        self.data.style = style;
+        self
+    }
+}
+
+impl<FD: FormToolData, FDT: FormOptions> ControlBuilder<FD, RadioButtonsData, FDT> {
+    /// Sets the options to the variants of `FDT`, and the parse functions
+    /// to turn the chosen option into its variant.
+    ///
+    /// This replaces any previous options. See [`FormOptions`].
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// types in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn options_from(mut self) -> Self {
+        self.data.options = FDT::variants()
+            .iter()
+            .map(|variant| (variant.display(), variant.value()))
+            .collect();
+        self.parse_options();
+        self
+    }
+}
+
+impl<FD: FormToolData> ControlBuilder<FD, RadioButtonsData, usize> {
+    /// Sets the parse functions to bind the radio buttons to the index of
+    /// the selected option, rather than its value.
+    ///
+    /// This must be called after all the options are added.
+    /// If the stored index is out of range, no option will be selected.
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// types in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn parse_index(mut self) -> Self {
+        let values = self
+            .data
+            .options
+            .iter()
+            .map(|(_, value)| value.clone())
+            .collect::<Vec<_>>();
+        let values_clone = values.clone();
+        self.parse_fn = Some(Box::new(move |control_return_value| {
+            values_clone
+                .iter()
+                .position(|value| *value == control_return_value)
+                .ok_or_else(|| String::from("Invalid option"))
+        }));
+        self.unparse_fn = Some(Box::new(move |index| {
+            values.get(index).cloned().unwrap_or_default()
+        }));
+        self.custom_parse_msg = false;
+        self
+    }
 }
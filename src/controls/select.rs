@@ -1,27 +1,61 @@
 use super::{
-    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidatedControlData,
-    ValidationState,
+    display::DisplayData, BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData,
+    FormOptions, ValidatedControlData, ValidationState,
 };
 use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
-use leptos::{IntoSignal, MaybeSignal, RwSignal, Signal, SignalGet, SignalSetter, View};
-use std::rc::Rc;
+use leptos::leptos_dom::helpers::TimeoutHandle;
+use leptos::{
+    create_effect, create_local_resource, create_rw_signal, on_cleanup, set_timeout_with_handle,
+    store_value, IntoSignal, MaybeSignal, RwSignal, Signal, SignalGet, SignalGetUntracked,
+    SignalSet, SignalSetter, SignalWith, SignalWithUntracked, StoredValue, View,
+};
+use std::{future::Future, rc::Rc, time::Duration};
 
 type DynamicOptionsGetter<FD> = Rc<dyn Fn(RwSignal<FD>) -> Vec<(String, String)> + 'static>;
+/// The options that are loaded asynchronously, which are `None` while
+/// loading.
+type LoadedOptions = Signal<Option<Result<Vec<(String, String)>, String>>>;
+/// Creates the signal for the async options.
+type AsyncOptionsGetter<FD> = Rc<dyn Fn(RwSignal<FD>) -> LoadedOptions + 'static>;
+/// Creates the signal for the searched options from the debounced search
+/// query.
+type SearchOptionsGetter = Rc<dyn Fn(Signal<String>) -> LoadedOptions + 'static>;
 /// Data used for building the select control.
 pub struct SelectBuildData<FD: FormToolData> {
     pub name: String,
-    pub label: Option<String>,
+    pub label: Option<MaybeSignal<String>>,
     /// A derived signal for dynamic options for the select
     ///
     /// This is just a temp value for building, and should not be used
     /// directly
     dynamic_options: Option<DynamicOptionsGetter<FD>>,
+    /// A function that creates the resource for async options
+    ///
+    /// This is just a temp value for building, and should not be used
+    /// directly
+    async_options: Option<AsyncOptionsGetter<FD>>,
+    /// A function that creates the resource for searched options, along
+    /// with how long to wait after typing before searching
+    ///
+    /// This is just a temp value for building, and should not be used
+    /// directly
+    search_options: Option<(Duration, SearchOptionsGetter)>,
     /// The options for the select.
     ///
     /// The first value is the string to display, the second is the value.
     pub options: MaybeSignal<Vec<(String, String)>>,
     /// The display text for the blank option, if there is one.
     pub blank_option: Option<String>,
+    /// The value of the blank option, which is an empty string by default.
+    pub blank_value: String,
+    /// The display text for the "other" option, if there is one.
+    ///
+    /// Choosing the "other" option shows a text input, and the text typed
+    /// in it becomes the control's value.
+    pub other_option: Option<String>,
+    /// The number of rows to show, rendering the select as a list box
+    /// rather than a dropdown.
+    pub size: Option<u32>,
 }
 impl<FD: FormToolData> Default for SelectBuildData<FD> {
     fn default() -> Self {
@@ -29,8 +63,13 @@ impl<FD: FormToolData> Default for SelectBuildData<FD> {
             name: String::default(),
             label: None,
             dynamic_options: None,
+            async_options: None,
+            search_options: None,
             options: MaybeSignal::default(),
             blank_option: None,
+            blank_value: String::new(),
+            other_option: None,
+            size: None,
         }
     }
 }
@@ -40,9 +79,38 @@ impl<FD: FormToolData> Clone for SelectBuildData<FD> {
             name: self.name.clone(),
             label: self.label.clone(),
             dynamic_options: self.dynamic_options.clone(),
+            async_options: self.async_options.clone(),
+            search_options: self.search_options.clone(),
             options: self.options.clone(),
             blank_option: self.blank_option.clone(),
+            blank_value: self.blank_value.clone(),
+            other_option: self.other_option.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<FD: FormToolData> SelectBuildData<FD> {
+    /// Gets the options of the select, which are the loaded
+    /// `async_options` if there are any.
+    fn options(
+        &self,
+        fd: RwSignal<FD>,
+        async_options: Option<LoadedOptions>,
+    ) -> MaybeSignal<Vec<(String, String)>> {
+        if let Some(async_options) = async_options {
+            let options = move || {
+                async_options.with(|o| o.as_ref().and_then(|o| o.clone().ok()).unwrap_or_default())
+            };
+            return MaybeSignal::Dynamic(options.into_signal());
         }
+        self.dynamic_options
+            .as_ref()
+            .map(|d| {
+                let d = d.clone();
+                MaybeSignal::Dynamic((move || d(fd)).into_signal())
+            })
+            .unwrap_or(self.options.clone())
     }
 }
 
@@ -50,18 +118,51 @@ impl<FD: FormToolData> Clone for SelectBuildData<FD> {
 #[derive(Default, Clone)]
 pub struct SelectData {
     pub name: String,
-    pub label: Option<String>,
+    pub label: Option<MaybeSignal<String>>,
     /// The options for the select.
     ///
     /// The first value is the string to display, the second is the value.
     pub options: MaybeSignal<Vec<(String, String)>>,
     /// The display text for the blank option, if there is one.
     pub blank_option: Option<String>,
+    /// The value of the blank option, which is an empty string by default.
+    pub blank_value: String,
+    /// The display text for the "other" option, if there is one.
+    ///
+    /// Choosing the "other" option shows a text input, and the text typed
+    /// in it becomes the control's value.
+    pub other_option: Option<String>,
+    /// The number of rows to show, rendering the select as a list box
+    /// rather than a dropdown.
+    pub size: Option<u32>,
+    /// The search query, if the options are searched.
+    ///
+    /// The style should render a search box that sets this.
+    pub search: Option<RwSignal<String>>,
+    /// Whether the options are still being loaded.
+    pub loading: MaybeSignal<bool>,
+    /// The error message if loading the options failed.
+    pub load_error: MaybeSignal<Option<String>>,
 }
 
 impl<FD: FormToolData> ControlData<FD> for SelectBuildData<FD> {
     type ReturnType = String;
 
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn value_text(&self, value: &Self::ReturnType) -> Option<String> {
+        // show the display string of the option
+        let display = self.options.with(|options| {
+            options
+                .iter()
+                .find(|(_, v)| v == value)
+                .map(|(display, _)| display.clone())
+        });
+        Some(display.unwrap_or_else(|| value.clone()))
+    }
+
     fn render_control<FS: FormStyle>(
         fs: &FS,
         fd: RwSignal<FD>,
@@ -69,33 +170,115 @@ impl<FD: FormToolData> ControlData<FD> for SelectBuildData<FD> {
         value_getter: Signal<Self::ReturnType>,
         value_setter: SignalSetter<Self::ReturnType>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View {
-        let options = control
-            .data
-            .dynamic_options
-            .as_ref()
-            .map(|d| {
-                let d = d.clone();
-                MaybeSignal::Dynamic((move || d(fd)).into_signal())
-            })
-            .unwrap_or(control.data.options.clone());
+        let mut loading = MaybeSignal::default();
+        let mut load_error = MaybeSignal::default();
+        let mut search = None;
+        let async_options = if let Some((delay, ref search_options)) = control.data.search_options {
+            let query = create_rw_signal(String::new());
+            search = Some(query);
+            Some(search_options(debounce(query, delay)))
+        } else {
+            control.data.async_options.as_ref().map(|a| a(fd))
+        };
+        if let Some(async_options) = async_options {
+            loading =
+                MaybeSignal::Dynamic((move || async_options.with(|o| o.is_none())).into_signal());
+            load_error = MaybeSignal::Dynamic(
+                (move || async_options.with(|o| o.as_ref().and_then(|o| o.clone().err())))
+                    .into_signal(),
+            );
+        }
+        let options = control.data.options(fd, async_options);
 
         let new_control = ControlRenderData {
             styles: control.styles.clone(),
+            conditional_styles: control.conditional_styles.clone(),
+            node_ref: control.node_ref,
+            tabindex: control.tabindex,
+            inline_errors: control.inline_errors,
+            native_validation: control.native_validation.clone(),
             data: SelectData {
                 name: control.data.name.clone(),
                 label: control.data.label.clone(),
                 options,
                 blank_option: control.data.blank_option.clone(),
+                blank_value: control.data.blank_value.clone(),
+                other_option: control.data.other_option.clone(),
+                size: control.data.size,
+                search,
+                loading,
+                load_error,
             },
         };
         let new_control = Rc::new(new_control);
 
-        fs.select(new_control, value_getter, value_setter, validation_state)
+        fs.select(
+            new_control,
+            value_getter,
+            value_setter,
+            validation_state,
+            touched,
+        )
+    }
+
+    fn render_display<FS: FormStyle>(
+        fs: &FS,
+        fd: RwSignal<FD>,
+        control: Rc<ControlRenderData<FS, Self>>,
+        value_getter: Signal<Self::ReturnType>,
+    ) -> View {
+        // show the display string of the selected option
+        let async_options = match control.data.search_options {
+            // search with an empty query, like the control does before the
+            // user types anything
+            Some((_, ref search_options)) => Some(search_options(Signal::derive(String::new))),
+            None => control.data.async_options.as_ref().map(|a| a(fd)),
+        };
+        let options = control.data.options(fd, async_options);
+        let value = move || {
+            let value = value_getter.get();
+            options.with(|options| {
+                options
+                    .iter()
+                    .find(|(_, v)| *v == value)
+                    .map(|(display, _)| display.clone())
+                    .unwrap_or(value)
+            })
+        };
+        DisplayData::render(
+            fs,
+            &control,
+            &control.data.name,
+            control.data.label.as_ref(),
+            value,
+        )
     }
 }
 impl<FD: FormToolData> ValidatedControlData<FD> for SelectBuildData<FD> {}
 
+/// Creates a signal that follows `query` once it stops changing for `delay`.
+fn debounce(query: RwSignal<String>, delay: Duration) -> Signal<String> {
+    let debounced = create_rw_signal(query.get_untracked());
+    let handle: StoredValue<Option<TimeoutHandle>> = store_value(None);
+    create_effect(move |_| {
+        let query = query.get();
+        if let Some(handle) = handle.get_value() {
+            handle.clear();
+        }
+        let new_handle = set_timeout_with_handle(move || debounced.set(query), delay).ok();
+        handle.set_value(new_handle);
+    });
+    // don't set the query after the select is removed
+    on_cleanup(move || {
+        if let Some(handle) = handle.try_get_value().flatten() {
+            handle.clear();
+        }
+    });
+    debounced.into()
+}
+
 impl<FD: FormToolData> FormBuilder<FD> {
     /// Builds a select control and adds it to the form.
     pub fn select<FDT: Clone + PartialEq + 'static>(
@@ -128,7 +311,15 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
 
     /// Sets the label for the select.
     pub fn labeled(mut self, label: impl ToString) -> Self {
-        self.data.label = Some(label.to_string());
+        self.data.label = Some(MaybeSignal::Static(label.to_string()));
+        self
+    }
+
+    /// Sets the label for the select to a signal.
+    ///
+    /// This is useful for labels that change, like localized labels.
+    pub fn labeled_signal(mut self, label: Signal<String>) -> Self {
+        self.data.label = Some(MaybeSignal::Dynamic(label));
         self
     }
 
@@ -136,8 +327,10 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
     ///
     /// This will overwrite any pervious options setting.
     pub fn with_options(mut self, options: impl Iterator<Item = impl ToString>) -> Self {
-        // clear dynamic option
+        // clear dynamic options
         self.data.dynamic_options = None;
+        self.data.async_options = None;
+        self.data.search_options = None;
 
         let options = options.map(|v| (v.to_string(), v.to_string())).collect();
         self.data.options = MaybeSignal::Static(options);
@@ -152,8 +345,10 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
         mut self,
         options: impl Iterator<Item = (impl ToString, impl ToString)>,
     ) -> Self {
-        // clear dynamic option
+        // clear dynamic options
         self.data.dynamic_options = None;
+        self.data.async_options = None;
+        self.data.search_options = None;
 
         let options = options
             .map(|(d, v)| (d.to_string(), v.to_string()))
@@ -166,8 +361,10 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
     ///
     /// This will overwrite any pervious options setting.
     pub fn with_options_signal(mut self, options: Signal<Vec<String>>) -> Self {
-        // clear dynamic option
+        // clear dynamic options
         self.data.dynamic_options = None;
+        self.data.async_options = None;
+        self.data.search_options = None;
 
         let options = move || {
             options
@@ -185,8 +382,10 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
     ///
     /// This will overwrite any pervious options setting.
     pub fn with_options_valued_signal(mut self, options: Signal<Vec<(String, String)>>) -> Self {
-        // clear dynamic option
+        // clear dynamic options
         self.data.dynamic_options = None;
+        self.data.async_options = None;
+        self.data.search_options = None;
 
         self.data.options = MaybeSignal::Dynamic(options);
         self
@@ -205,6 +404,8 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
                 .map(|v| (v.clone(), v))
                 .collect::<Vec<_>>()
         };
+        self.data.async_options = None;
+        self.data.search_options = None;
         self.data.dynamic_options = Some(Rc::new(derived_signal));
         self
     }
@@ -217,10 +418,74 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
         mut self,
         derived_signal: impl Fn(RwSignal<FD>) -> Vec<(String, String)> + 'static,
     ) -> Self {
+        self.data.async_options = None;
+        self.data.search_options = None;
         self.data.dynamic_options = Some(Rc::new(derived_signal));
         self
     }
 
+    /// Sets the options to the (display_string, value) pairs loaded
+    /// asynchronously by `fetcher`.
+    ///
+    /// The options are loaded again whenever the value returned by `source`
+    /// changes. While loading, a disabled loading option is shown. If the
+    /// fetcher returns an error, the error message is shown instead.
+    ///
+    /// This will overwrite any pervious options setting.
+    pub fn with_async_options<S, Fut>(
+        mut self,
+        source: impl Fn(&FD) -> S + 'static,
+        fetcher: impl Fn(S) -> Fut + 'static,
+    ) -> Self
+    where
+        S: PartialEq + Clone + 'static,
+        Fut: Future<Output = Result<Vec<(String, String)>, String>> + 'static,
+    {
+        let source = Rc::new(source);
+        let fetcher = Rc::new(fetcher);
+        let async_options = move |fd: RwSignal<FD>| {
+            let source = source.clone();
+            let fetcher = fetcher.clone();
+            let resource =
+                create_local_resource(move || fd.with(|fd| source(fd)), move |s| fetcher(s));
+            (move || resource.get()).into_signal()
+        };
+        self.data.dynamic_options = None;
+        self.data.search_options = None;
+        self.data.async_options = Some(Rc::new(async_options));
+        self
+    }
+
+    /// Sets the options to the (display_string, value) pairs found by
+    /// searching with `fetcher`.
+    ///
+    /// A search box is shown with the select. Once the user stops typing in
+    /// it for `delay`, the options are loaded again by calling `fetcher`
+    /// with the search query. Results from an earlier query that finish
+    /// after a later one are ignored, so only the results for the latest
+    /// query are shown.
+    ///
+    /// This will overwrite any pervious options setting.
+    pub fn with_search_options<Fut>(
+        mut self,
+        delay: Duration,
+        fetcher: impl Fn(String) -> Fut + 'static,
+    ) -> Self
+    where
+        Fut: Future<Output = Result<Vec<(String, String)>, String>> + 'static,
+    {
+        let fetcher = Rc::new(fetcher);
+        let search_options = move |query: Signal<String>| {
+            let fetcher = fetcher.clone();
+            let resource = create_local_resource(move || query.get(), move |q| fetcher(q));
+            (move || resource.get()).into_signal()
+        };
+        self.data.dynamic_options = None;
+        self.data.async_options = None;
+        self.data.search_options = Some((delay, Rc::new(search_options)));
+        self
+    }
+
     /// Adds a blank option as the first option for the select.
     pub fn with_blank_option(mut self) -> Self {
         self.data.blank_option = Some(String::new());
@@ -233,4 +498,124 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
         self.data.blank_option = Some(display.to_string());
         self
     }
+
+    /// Adds a blank option as the first option for the select, with the
+    /// given display string and value.
+    ///
+    /// This is for when the field unparses to something other than an
+    /// empty string when nothing is chosen, like `"0"`.
+    pub fn with_blank_option_value(mut self, display: impl ToString, value: impl ToString) -> Self {
+        self.data.blank_option = Some(display.to_string());
+        self.data.blank_value = value.to_string();
+        self
+    }
+
+    /// Requires that an option other than the blank option is chosen.
+    ///
+    /// This fails validation with the given message while the field
+    /// unparses to the blank option's value. This runs after any
+    /// [`validation_fn`](Self::validation_fn).
+    pub fn require_selection(mut self, msg: impl ToString) -> Self
+    where
+        FDT: 'static,
+    {
+        let msg = msg.to_string();
+        self.deferred_validations
+            .push(Box::new(move |data, getter, unparse_fn| {
+                let blank_value = data.blank_value.clone();
+                Rc::new(move |fd: &FD| {
+                    if unparse_fn(getter(fd)) == blank_value {
+                        Err(msg.clone())
+                    } else {
+                        Ok(())
+                    }
+                })
+            }));
+        self
+    }
+
+    /// Sets the number of rows to show.
+    ///
+    /// This renders the select as a list box with `size` visible rows,
+    /// rather than a dropdown.
+    pub fn size(mut self, size: u32) -> Self {
+        self.data.size = Some(size);
+        self
+    }
+
+    /// Adds an "Other" option as the last option for the select.
+    ///
+    /// Choosing it shows a text input for a custom value, and whatever is
+    /// typed there becomes the control's value. Validation applies to the
+    /// typed value.
+    pub fn with_other_option(self) -> Self {
+        self.with_other_option_displayed("Other")
+    }
+
+    /// Adds an "other" option as the last option for the select, but sets
+    /// the display string to the given value.
+    ///
+    /// Choosing it shows a text input for a custom value, and whatever is
+    /// typed there becomes the control's value. Validation applies to the
+    /// typed value.
+    pub fn with_other_option_displayed(mut self, display: impl ToString) -> Self {
+        self.data.other_option = Some(display.to_string());
+        self
+    }
+}
+
+impl<FD: FormToolData, FDT: FormOptions> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
+    /// Sets the options to the variants of `FDT`, and the parse functions
+    /// to turn the selected option into its variant.
+    ///
+    /// This will overwrite any pervious options setting. See
+    /// [`FormOptions`].
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// types in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn options_from(self) -> Self {
+        let options = FDT::variants()
+            .iter()
+            .map(|variant| (variant.display(), variant.value()))
+            .collect::<Vec<_>>();
+        let mut builder = self.with_options_valued(options.into_iter());
+        builder.parse_options();
+        builder
+    }
+}
+
+impl<FD: FormToolData> ControlBuilder<FD, SelectBuildData<FD>, usize> {
+    /// Sets the parse functions to bind the select to the index of the
+    /// selected option, rather than its value.
+    ///
+    /// This must be called after the options are set, and only works with
+    /// the options set by `with_options*`, not dynamic or async options.
+    /// If the stored index is out of range, no option will be selected.
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// types in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn parse_index(mut self) -> Self {
+        let options = self.data.options.clone();
+        let options_clone = options.clone();
+        self.parse_fn = Some(Box::new(move |control_return_value| {
+            options_clone.with_untracked(|options| {
+                options
+                    .iter()
+                    .position(|(_, value)| *value == control_return_value)
+                    .ok_or_else(|| String::from("Invalid option"))
+            })
+        }));
+        self.unparse_fn = Some(Box::new(move |index| {
+            options.with_untracked(|options| {
+                options
+                    .get(index)
+                    .map(|(_, value)| value.clone())
+                    .unwrap_or_default()
+            })
+        }));
+        self.custom_parse_msg = false;
+        self
+    }
 }
@@ -1,24 +1,83 @@
 use super::{
-    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidationState,
+    display::DisplayData, with_unit, BuilderCxFn, BuilderFn, ControlBuilder, ControlData,
+    ControlRenderData, Primitive, ScaleFactor, ValidationState,
 };
 use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
-use leptos::{MaybeSignal, RwSignal, Signal, SignalSetter, View};
-use std::rc::Rc;
+use leptos::{MaybeSignal, RwSignal, Signal, SignalGet, SignalSetter, View};
+use std::{fmt::Display, rc::Rc};
 
 /// Data used for the slider control.
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct SliderData {
     pub name: String,
-    pub label: Option<String>,
+    pub label: Option<MaybeSignal<String>>,
     pub step: Option<MaybeSignal<String>>,
     pub min: Option<MaybeSignal<String>>,
     pub max: Option<MaybeSignal<String>>,
+    /// The range of the slider, if it uses a logarithmic scale.
+    pub logarithmic: Option<LogRange>,
+    /// The unit shown next to the value, like "kg".
+    pub unit: Option<String>,
+    /// The factor that the shown value is multiplied by to get the stored
+    /// value, if the slider is [`scaled`](ControlBuilder::scaled).
+    pub scale: Option<ScaleFactor>,
+    /// The value to send with the form, if it is different from the value
+    /// of the slider.
+    ///
+    /// This is set when the control is rendered.
+    pub submitted_value: Option<Signal<String>>,
+}
+
+/// The range of a slider that uses a logarithmic scale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LogRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+// the bounds are checked to be finite when the range is set, so they are
+// never NaN
+impl Eq for LogRange {}
+
+/// The number of positions the slider has when using a logarithmic scale.
+const LOG_STEPS: f64 = 1000.0;
+
+impl SliderData {
+    /// Maps a value to the position of the slider on a logarithmic scale.
+    fn log_position(value: &str, min: f64, max: f64) -> String {
+        let Ok(value) = value.trim().parse::<f64>() else {
+            return String::from("0");
+        };
+        let value = value.clamp(min, max);
+        let position = (value / min).ln() / (max / min).ln() * LOG_STEPS;
+        position.round().to_string()
+    }
+
+    /// Maps the position of the slider on a logarithmic scale to a value.
+    ///
+    /// The value is rounded to 3 significant figures.
+    fn log_value(position: &str, min: f64, max: f64) -> String {
+        let position = position.parse::<f64>().unwrap_or_default();
+        let value = min * (max / min).powf(position / LOG_STEPS);
+        let precision = 10f64.powf(value.log10().floor() - 2.0);
+        let value = (value / precision).round() * precision;
+        let decimals = (2.0 - value.log10().floor()).max(0.0) as usize;
+        format!("{:.*}", decimals, value)
+    }
 }
 
 impl<FD: FormToolData> ControlData<FD> for SliderData {
     /// String to support integers or decimal point types.
     type ReturnType = String;
 
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn value_text(&self, value: &Self::ReturnType) -> Option<String> {
+        Some(with_unit(value.clone(), &self.unit))
+    }
+
     fn render_control<FS: FormStyle>(
         fs: &FS,
         _fd: RwSignal<FD>,
@@ -26,8 +85,78 @@ impl<FD: FormToolData> ControlData<FD> for SliderData {
         value_getter: Signal<Self::ReturnType>,
         value_setter: SignalSetter<Self::ReturnType>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
+    ) -> View {
+        // the form should send the stored value, not the slider's value
+        let scale = control.data.scale;
+        let submitted_value = Signal::derive(move || match scale {
+            Some(scale) => scale.stored_value(&value_getter.get()),
+            None => value_getter.get(),
+        });
+
+        let Some(LogRange { min, max }) = control.data.logarithmic else {
+            if scale.is_none() {
+                return fs.slider(
+                    control,
+                    value_getter,
+                    value_setter,
+                    validation_state,
+                    touched,
+                );
+            }
+            let render_data = control
+                .with_data(SliderData {
+                    submitted_value: Some(submitted_value),
+                    ..control.data.clone()
+                })
+                .without_native_range();
+            return fs.slider(
+                Rc::new(render_data),
+                value_getter,
+                value_setter,
+                validation_state,
+                touched,
+            );
+        };
+
+        // the html slider is linear, so map its position to the real value
+        let render_data = control
+            .with_data(SliderData {
+                step: Some(MaybeSignal::Static(String::from("1"))),
+                min: Some(MaybeSignal::Static(String::from("0"))),
+                max: Some(MaybeSignal::Static(LOG_STEPS.to_string())),
+                submitted_value: Some(submitted_value),
+                ..control.data.clone()
+            })
+            .without_native_range();
+        let position_getter =
+            Signal::derive(move || SliderData::log_position(&value_getter.get(), min, max));
+        let position_setter = SignalSetter::map(move |position: String| {
+            value_setter.set(SliderData::log_value(&position, min, max))
+        });
+        fs.slider(
+            Rc::new(render_data),
+            position_getter,
+            position_setter,
+            validation_state,
+            touched,
+        )
+    }
+
+    fn render_display<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: Rc<ControlRenderData<FS, Self>>,
+        value_getter: Signal<Self::ReturnType>,
     ) -> View {
-        fs.slider(control, value_getter, value_setter, validation_state)
+        let unit = control.data.unit.clone();
+        DisplayData::render(
+            fs,
+            &control,
+            &control.data.name,
+            control.data.label.as_ref(),
+            move || with_unit(value_getter.get(), &unit),
+        )
     }
 }
 
@@ -63,7 +192,15 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SliderData, FDT> {
 
     /// Sets the label for the slider.
     pub fn labeled(mut self, label: impl ToString) -> Self {
-        self.data.label = Some(label.to_string());
+        self.data.label = Some(MaybeSignal::Static(label.to_string()));
+        self
+    }
+
+    /// Sets the label for the slider to a signal.
+    ///
+    /// This is useful for labels that change, like localized labels.
+    pub fn labeled_signal(mut self, label: Signal<String>) -> Self {
+        self.data.label = Some(MaybeSignal::Dynamic(label));
         self
     }
 
@@ -80,6 +217,10 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SliderData, FDT> {
     }
 
     /// Sets the minimum value for the slider.
+    ///
+    /// This only sets the slider's minimum, the form data can still hold
+    /// a lower value. To also clamp the parsed value, use
+    /// [`min_num`](Self::min_num).
     pub fn min(mut self, min: impl ToString) -> Self {
         self.data.min = Some(MaybeSignal::Static(min.to_string()));
         self
@@ -92,6 +233,10 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SliderData, FDT> {
     }
 
     /// Sets the maximum value for the slider.
+    ///
+    /// This only sets the slider's maximum, the form data can still hold
+    /// a higher value. To also clamp the parsed value, use
+    /// [`max_num`](Self::max_num).
     pub fn max(mut self, max: impl ToString) -> Self {
         self.data.max = Some(MaybeSignal::Static(max.to_string()));
         self
@@ -102,4 +247,110 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SliderData, FDT> {
         self.data.max = Some(MaybeSignal::Dynamic(max));
         self
     }
+
+    /// Makes the slider use a logarithmic scale from `min` to `max`.
+    ///
+    /// This is useful for values that span orders of magnitude, like 1 to
+    /// 1,000,000. The value stored is the real number, rounded to 3
+    /// significant figures, while the position of the slider is log-mapped.
+    /// This overrides the [`min`](Self::min)(), [`max`](Self::max)(), and
+    /// [`step`](Self::step)() settings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min` is not positive, if `max` is not greater than `min`,
+    /// or if `max` is not finite.
+    pub fn logarithmic(mut self, min: f64, max: f64) -> Self {
+        if !(min > 0.0 && max > min && max.is_finite()) {
+            panic!(
+                "Invalid logarithmic slider range ({}, {}): min must be positive and less than max",
+                min, max
+            );
+        }
+        self.data.logarithmic = Some(LogRange { min, max });
+        self
+    }
+
+    /// Shows the unit next to the slider, like "kg".
+    ///
+    /// This only changes what is shown. To store the value in a different
+    /// unit than it is shown in, see [`scaled`](Self::scaled).
+    pub fn unit(mut self, unit: impl ToString) -> Self {
+        self.data.unit = Some(unit.to_string());
+        self
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, SliderData, FDT>
+where
+    FDT: PartialOrd + Display + Clone + 'static,
+{
+    /// Sets the step ammount using the field's numeric type.
+    pub fn step_num(mut self, step: FDT) -> Self {
+        self.data.step = Some(MaybeSignal::Static(step.to_string()));
+        self
+    }
+
+    /// Sets the minimum value for the slider using the field's numeric
+    /// type.
+    ///
+    /// Along with setting the slider's minimum, parsed values below `min`
+    /// are clamped to `min`, unlike with [`min`](Self::min).
+    pub fn min_num(mut self, min: FDT) -> Self {
+        self.data.min = Some(MaybeSignal::Static(min.to_string()));
+        self.add_range_fn(move |value| Ok(if value < min { min.clone() } else { value }));
+        self
+    }
+
+    /// Sets the maximum value for the slider using the field's numeric
+    /// type.
+    ///
+    /// Along with setting the slider's maximum, parsed values above `max`
+    /// are clamped to `max`, unlike with [`max`](Self::max).
+    pub fn max_num(mut self, max: FDT) -> Self {
+        self.data.max = Some(MaybeSignal::Static(max.to_string()));
+        self.add_range_fn(move |value| Ok(if value > max { max.clone() } else { value }));
+        self
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, SliderData, FDT>
+where
+    FDT: Clone + Into<f64> + 'static,
+{
+    /// Snaps the value to the nearest of the `allowed` values, like shoe
+    /// sizes (6, 6.5, 7, 8, 10).
+    ///
+    /// The slider can still be dragged between its [`step`](Self::step)s,
+    /// and moves to the snapped value once it loses focus.
+    pub fn snap_to(mut self, allowed: Vec<FDT>) -> Self {
+        self.add_snap_fn(allowed);
+        self
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, SliderData, FDT>
+where
+    FDT: Primitive,
+{
+    /// Stores the value as the value shown in the slider times `factor`.
+    ///
+    /// This sets the parse functions, so it is useful for showing a
+    /// different unit than what is stored, like showing kilograms while
+    /// storing grams with a `factor` of 1000. The slider's `min`, `max`, and
+    /// `step` are in the shown unit, so they should be set with
+    /// `min`, `max`, and `step` rather than their `_num` versions.
+    ///
+    /// When the form is submitted by the browser, like with an `ActionForm`,
+    /// the stored value is sent rather than the shown value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is zero or not finite.
+    pub fn scaled(mut self, factor: f64) -> Self {
+        let scale = ScaleFactor::new(factor);
+        self.data.scale = Some(scale);
+        self.parse_scaled(scale);
+        self
+    }
 }
@@ -1,25 +1,61 @@
 use super::{
-    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidatedControlData,
-    ValidationState,
+    display::DisplayData, with_unit, BuilderCxFn, BuilderFn, ControlBuilder, ControlData,
+    ControlRenderData, Primitive, ScaleFactor, ValidatedControlData, ValidationState,
 };
 use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
-use leptos::{MaybeSignal, RwSignal, Signal, SignalSetter, View};
-use std::rc::Rc;
+use leptos::{MaybeSignal, RwSignal, Signal, SignalGet, SignalSetter, View};
+use std::{fmt::Display, rc::Rc};
 
 /// Data used for the stepper control.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct StepperData {
     pub name: String,
-    pub label: Option<String>,
+    pub label: Option<MaybeSignal<String>>,
     pub step: Option<MaybeSignal<String>>,
     pub min: Option<MaybeSignal<String>>,
     pub max: Option<MaybeSignal<String>>,
+    /// Whether the stepper is read only.
+    pub readonly: bool,
+    /// Whether to block input that is not part of a number.
+    pub numeric_only: bool,
+    /// The unit shown next to the value, like "kg".
+    pub unit: Option<String>,
+    /// The factor that the shown value is multiplied by to get the stored
+    /// value, if the stepper is [`scaled`](ControlBuilder::scaled).
+    pub scale: Option<ScaleFactor>,
+    /// The value to send with the form, if it is different from the shown
+    /// value.
+    ///
+    /// This is set when the control is rendered.
+    pub submitted_value: Option<Signal<String>>,
+}
+
+impl StepperData {
+    /// Checks if inserting `text` into an input with the `current` value
+    /// would keep it numeric.
+    ///
+    /// Only digits, one decimal point and one minus sign are allowed.
+    pub fn is_numeric_input(current: &str, text: &str) -> bool {
+        let count = |c: char| current.matches(c).count() + text.matches(c).count();
+        text.chars()
+            .all(|c| c.is_ascii_digit() || c == '.' || c == '-')
+            && count('.') <= 1
+            && count('-') <= 1
+    }
 }
 
 impl<FD: FormToolData> ControlData<FD> for StepperData {
     /// String, as a user can still enter characters in a number fields.
     type ReturnType = String;
 
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn value_text(&self, value: &Self::ReturnType) -> Option<String> {
+        Some(with_unit(value.clone(), &self.unit))
+    }
+
     fn render_control<FS: FormStyle>(
         fs: &FS,
         _fd: RwSignal<FD>,
@@ -27,8 +63,49 @@ impl<FD: FormToolData> ControlData<FD> for StepperData {
         value_getter: Signal<Self::ReturnType>,
         value_setter: SignalSetter<Self::ReturnType>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
+    ) -> View {
+        let Some(scale) = control.data.scale else {
+            return fs.stepper(
+                control,
+                value_getter,
+                value_setter,
+                validation_state,
+                touched,
+            );
+        };
+
+        // the form should send the stored value, not the shown value
+        let submitted_value = Signal::derive(move || scale.stored_value(&value_getter.get()));
+        let render_data = control
+            .with_data(StepperData {
+                submitted_value: Some(submitted_value),
+                ..control.data.clone()
+            })
+            .without_native_range();
+        fs.stepper(
+            Rc::new(render_data),
+            value_getter,
+            value_setter,
+            validation_state,
+            touched,
+        )
+    }
+
+    fn render_display<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: Rc<ControlRenderData<FS, Self>>,
+        value_getter: Signal<Self::ReturnType>,
     ) -> View {
-        fs.stepper(control, value_getter, value_setter, validation_state)
+        let unit = control.data.unit.clone();
+        DisplayData::render(
+            fs,
+            &control,
+            &control.data.name,
+            control.data.label.as_ref(),
+            move || with_unit(value_getter.get(), &unit),
+        )
     }
 }
 impl<FD: FormToolData> ValidatedControlData<FD> for StepperData {}
@@ -65,7 +142,15 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, StepperData, FDT> {
 
     /// Sets the label of the stepper.
     pub fn labeled(mut self, label: impl ToString) -> Self {
-        self.data.label = Some(label.to_string());
+        self.data.label = Some(MaybeSignal::Static(label.to_string()));
+        self
+    }
+
+    /// Sets the label for the stepper to a signal.
+    ///
+    /// This is useful for labels that change, like localized labels.
+    pub fn labeled_signal(mut self, label: Signal<String>) -> Self {
+        self.data.label = Some(MaybeSignal::Dynamic(label));
         self
     }
 
@@ -82,6 +167,10 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, StepperData, FDT> {
     }
 
     /// Sets the minimum value for the stepper.
+    ///
+    /// This only limits the stepper's arrows and the browser's own
+    /// validation, a lower value can still be typed in. To also clamp the
+    /// parsed value, use [`min_num`](Self::min_num).
     pub fn min(mut self, min: impl ToString) -> Self {
         self.data.min = Some(MaybeSignal::Static(min.to_string()));
         self
@@ -94,6 +183,10 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, StepperData, FDT> {
     }
 
     /// Sets the maximum value for the stepper.
+    ///
+    /// This only limits the stepper's arrows and the browser's own
+    /// validation, a higher value can still be typed in. To also clamp the
+    /// parsed value, use [`max_num`](Self::max_num).
     pub fn max(mut self, max: impl ToString) -> Self {
         self.data.max = Some(MaybeSignal::Static(max.to_string()));
         self
@@ -104,4 +197,106 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, StepperData, FDT> {
         self.data.max = Some(MaybeSignal::Dynamic(max));
         self
     }
+
+    /// Makes the stepper read only.
+    ///
+    /// Unlike a disabled input, a read only input can still be focused and
+    /// its value is still sent with the form.
+    pub fn readonly(mut self) -> Self {
+        self.data.readonly = true;
+        self
+    }
+
+    /// Blocks typing or pasting anything other than digits, a decimal
+    /// point, or a minus sign into the stepper.
+    ///
+    /// Navigation keys and selection are not affected.
+    pub fn numeric_only(mut self) -> Self {
+        self.data.numeric_only = true;
+        self
+    }
+
+    /// Shows the unit next to the stepper, like "kg".
+    ///
+    /// This only changes what is shown. To store the value in a different
+    /// unit than it is shown in, see [`scaled`](Self::scaled).
+    pub fn unit(mut self, unit: impl ToString) -> Self {
+        self.data.unit = Some(unit.to_string());
+        self
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, StepperData, FDT>
+where
+    FDT: PartialOrd + Display + Clone + 'static,
+{
+    /// Sets the step ammount using the field's numeric type.
+    pub fn step_num(mut self, step: FDT) -> Self {
+        self.data.step = Some(MaybeSignal::Static(step.to_string()));
+        self
+    }
+
+    /// Sets the minimum value for the stepper using the field's numeric
+    /// type.
+    ///
+    /// Along with setting the stepper's minimum, parsed values below `min`
+    /// are clamped to `min`, unlike with [`min`](Self::min). The clamped
+    /// value is shown once the stepper loses focus.
+    pub fn min_num(mut self, min: FDT) -> Self {
+        self.data.min = Some(MaybeSignal::Static(min.to_string()));
+        self.add_range_fn(move |value| Ok(if value < min { min.clone() } else { value }));
+        self
+    }
+
+    /// Sets the maximum value for the stepper using the field's numeric
+    /// type.
+    ///
+    /// Along with setting the stepper's maximum, parsed values above `max`
+    /// are clamped to `max`, unlike with [`max`](Self::max). The clamped
+    /// value is shown once the stepper loses focus.
+    pub fn max_num(mut self, max: FDT) -> Self {
+        self.data.max = Some(MaybeSignal::Static(max.to_string()));
+        self.add_range_fn(move |value| Ok(if value > max { max.clone() } else { value }));
+        self
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, StepperData, FDT>
+where
+    FDT: Clone + Into<f64> + 'static,
+{
+    /// Snaps the value to the nearest of the `allowed` values, like shoe
+    /// sizes (6, 6.5, 7, 8, 10).
+    ///
+    /// A value typed in is snapped once the stepper loses focus.
+    pub fn snap_to(mut self, allowed: Vec<FDT>) -> Self {
+        self.add_snap_fn(allowed);
+        self
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, StepperData, FDT>
+where
+    FDT: Primitive,
+{
+    /// Stores the value as the value shown in the stepper times `factor`.
+    ///
+    /// This sets the parse functions, so it is useful for showing a
+    /// different unit than what is stored, like showing kilograms while
+    /// storing grams with a `factor` of 1000. The stepper's `min`, `max`, and
+    /// `step` are in the shown unit, so they should be set with
+    /// `min`, `max`, and `step` rather than their `_num` versions.
+    ///
+    /// When the form is submitted by the browser, like with an `ActionForm`,
+    /// the stored value is sent rather than the shown value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is zero or not finite.
+    pub fn scaled(mut self, factor: f64) -> Self {
+        let scale = ScaleFactor::new(factor);
+        self.data.scale = Some(scale);
+        self.parse_scaled(scale);
+        self
+    }
 }
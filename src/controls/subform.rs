@@ -0,0 +1,143 @@
+use super::{
+    ControlRenderData, FieldGetter, FieldSetter, RenderState, SimulateState, ValidationCb,
+};
+use crate::styles::FormStyle;
+use crate::{form::FormToolData, form_builder::FormBuilder};
+use leptos::{
+    create_effect, create_rw_signal, CollectView, RwSignal, SignalGetUntracked, SignalSet,
+    SignalUpdate, SignalWith, SignalWithUntracked,
+};
+use std::rc::Rc;
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Embeds the form of another [`FormToolData`] type in this form.
+    ///
+    /// This allows a reusable form, like an address form, to be used in
+    /// many forms. The subform's controls are laid out with its
+    /// [`build_form`](FormToolData::build_form) method and rendered like a
+    /// [`group`](Self::group)(). The `getter` and `setter` map the subform's
+    /// data into and out of this form's data.
+    ///
+    /// The subform's validations are added to this form's validations, so
+    /// they also run when validating this form.
+    pub fn subform<SubFD>(
+        mut self,
+        getter: impl FieldGetter<FD, SubFD>,
+        setter: impl FieldSetter<FD, SubFD>,
+    ) -> Self
+    where
+        SubFD: FormToolData<Style = FD::Style, Context = FD::Context> + PartialEq,
+    {
+        let getter = Rc::new(getter);
+        let setter = Rc::new(setter);
+        let sub_builder = SubFD::build_form(FormBuilder::new_group(self.cx.clone()));
+
+        let validations = FormBuilder::<SubFD>::gate_validations(
+            sub_builder.validations,
+            sub_builder.validation_gate,
+        );
+        for validation in validations.clone() {
+            let getter = getter.clone();
+            let validation = move |fd: &FD| validation(&getter(fd));
+            self.validations.push(Rc::new(validation));
+        }
+        for exclusion in sub_builder.exclusions {
+            let (getter, setter) = (getter.clone(), setter.clone());
+            let exclusion = move |fd: &mut FD| {
+                let mut sub_fd = getter(fd);
+                exclusion(&mut sub_fd);
+                setter(fd, sub_fd);
+            };
+            self.exclusions.push(Rc::new(exclusion));
+        }
+        for (name, simulator) in sub_builder.simulators {
+            let (getter, setter) = (getter.clone(), setter.clone());
+            let validations = validations.clone();
+            let simulator = move |fd: &mut FD, value, state: &SimulateState<FD>| {
+                let sub_state = SimulateState {
+                    parse_error_fn: state.parse_error_fn.clone(),
+                    validations: validations.clone(),
+                };
+                let mut sub_fd = getter(fd);
+                let validation_state = simulator(&mut sub_fd, value, &sub_state);
+                setter(fd, sub_fd);
+                validation_state
+            };
+            self.simulators.push((name, Rc::new(simulator)));
+        }
+        self.field_names.extend(sub_builder.field_names);
+
+        let render_fns = sub_builder.render_fns;
+        let styles = sub_builder.styles;
+        let render_fn =
+            move |fs: Rc<FD::Style>, fd: RwSignal<FD>, render_state: RenderState<FD>| {
+                let sub_fd = create_rw_signal(fd.with_untracked(|fd| getter(fd)));
+
+                // keep the subform's data and this form's data in sync
+                let getter_clone = getter.clone();
+                create_effect(move |_| {
+                    let new_sub_fd = fd.with(|fd| getter_clone(fd));
+                    if sub_fd.with_untracked(|sub_fd| *sub_fd != new_sub_fd) {
+                        sub_fd.set(new_sub_fd);
+                    }
+                });
+                let setter_clone = setter.clone();
+                create_effect(move |_| {
+                    sub_fd.track();
+                    let new_sub_fd = sub_fd.get_untracked();
+                    if fd.with_untracked(|fd| getter(fd) != new_sub_fd) {
+                        fd.update(|fd| setter_clone(fd, new_sub_fd));
+                    }
+                });
+
+                let on_field_change = render_state.on_field_change.map(|on_field_change| {
+                    let on_sub_field_change = move |name: &str, sub_fd: &SubFD| {
+                        let mut fd = fd.get_untracked();
+                        setter(&mut fd, sub_fd.clone());
+                        on_field_change(name, &fd);
+                    };
+                    Rc::new(on_sub_field_change) as Rc<_>
+                });
+                let sub_render_state = RenderState {
+                    control_states: render_state.control_states,
+                    mode: render_state.mode,
+                    error_display: render_state.error_display,
+                    on_field_change,
+                    wrap_each: render_state.wrap_each.clone(),
+                    parse_error_fn: render_state.parse_error_fn.clone(),
+                    submitting: render_state.submitting,
+                    native_validation: render_state.native_validation,
+                };
+
+                let (views, validation_cbs): (Vec<_>, Vec<_>) = render_fns
+                    .into_iter()
+                    .map(|r_fn| r_fn(fs.clone(), sub_fd, sub_render_state.clone()))
+                    .unzip();
+
+                let render_data = Rc::new(ControlRenderData {
+                    data: views.collect_view(),
+                    styles,
+                    conditional_styles: Vec::new(),
+                    node_ref: None,
+                    tabindex: None,
+                    inline_errors: true,
+                    native_validation: None,
+                });
+                let view = fs.group(render_data);
+
+                let validation_cb = move || {
+                    let mut success = true;
+                    for validation in validation_cbs.iter().flatten() {
+                        if !validation() {
+                            success = false;
+                        }
+                    }
+                    success
+                };
+                (view, Some(Box::new(validation_cb) as Box<dyn ValidationCb>))
+            };
+
+        self.render_fns.push(Box::new(render_fn));
+        self
+    }
+}
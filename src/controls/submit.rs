@@ -8,7 +8,19 @@ use std::rc::Rc;
 
 /// Data used for the submit button control.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct SubmitData;
+pub struct SubmitData {
+    /// The url to submit to when this button is clicked, overriding the
+    /// form's action.
+    pub formaction: Option<String>,
+    /// The http method to submit with when this button is clicked,
+    /// overriding the form's method.
+    pub formmethod: Option<String>,
+    /// The text announced to screen readers while the form is being
+    /// submitted.
+    ///
+    /// If `None`, the style's default is used.
+    pub pending_text: Option<String>,
+}
 
 impl<FD: FormToolData> VanityControlData<FD> for SubmitData {
     fn render_control<FS: FormStyle>(
@@ -17,24 +29,45 @@ impl<FD: FormToolData> VanityControlData<FD> for SubmitData {
         control: Rc<ControlRenderData<FS, Self>>,
         value_getter: Option<Signal<String>>,
     ) -> View {
-        fs.submit(control, value_getter)
+        // the form renders its submit buttons with whether it is being
+        // submitted, see `add_submit`
+        fs.submit(control, value_getter, Signal::derive(|| false))
     }
 }
 impl<FD: FormToolData> GetterVanityControlData<FD> for SubmitData {}
 
 impl<FD: FormToolData> FormBuilder<FD> {
     /// Builds a submit button and adds it to the form.
-    pub fn submit(self, builder: impl BuilderFn<VanityControlBuilder<FD, SubmitData>>) -> Self {
-        self.new_vanity(builder)
+    ///
+    /// The button is disabled while the form is being submitted, and the
+    /// style announces it to screen readers.
+    pub fn submit(mut self, builder: impl BuilderFn<VanityControlBuilder<FD, SubmitData>>) -> Self {
+        let control = builder(VanityControlBuilder::new(SubmitData::default()));
+        self.add_submit(control);
+        self
     }
 
     /// Builds a submit button using the form's context and adds it to the
     /// form.
     pub fn submit_cx(
-        self,
+        mut self,
         builder: impl BuilderCxFn<VanityControlBuilder<FD, SubmitData>, FD::Context>,
     ) -> Self {
-        self.new_vanity_cx(builder)
+        let control = builder(
+            VanityControlBuilder::new(SubmitData::default()),
+            self.cx.clone(),
+        );
+        self.add_submit(control);
+        self
+    }
+
+    fn add_submit(&mut self, control: VanityControlBuilder<FD, SubmitData>) {
+        self.add_vanity_with_render(control, |_, render_state| {
+            let pending = render_state.submitting;
+            move |fs: &FD::Style, _, control, value_getter| {
+                fs.submit(control, value_getter, pending)
+            }
+        });
     }
 }
 
@@ -47,4 +80,30 @@ impl<FD: FormToolData> VanityControlBuilder<FD, SubmitData> {
         self.getter = Some(Rc::new(move |_| text.clone()));
         self
     }
+
+    /// Sets the url that the form is submitted to when this button is
+    /// clicked.
+    ///
+    /// This allows a form to have multiple submit buttons that go to
+    /// different urls, like "Save" and "Save & Continue". This only
+    /// applies to forms that are submitted to a url, not forms that call
+    /// a server function directly.
+    pub fn formaction(mut self, url: impl ToString) -> Self {
+        self.data.formaction = Some(url.to_string());
+        self
+    }
+
+    /// Sets the http method that the form is submitted with when this
+    /// button is clicked, like "get" or "post".
+    pub fn formmethod(mut self, method: impl ToString) -> Self {
+        self.data.formmethod = Some(method.to_string());
+        self
+    }
+
+    /// Sets the text announced to screen readers while the form is being
+    /// submitted, like "Submitting…".
+    pub fn pending_text(mut self, text: impl ToString) -> Self {
+        self.data.pending_text = Some(text.to_string());
+        self
+    }
 }
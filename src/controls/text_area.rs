@@ -1,23 +1,106 @@
 use super::{
-    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, UpdateEvent,
-    ValidatedControlData, ValidationState,
+    display::DisplayData, Autocapitalize, BuilderCxFn, BuilderFn, ControlBuilder, ControlData,
+    ControlRenderData, UpdateEvent, ValidatedControlData, ValidationState,
 };
 use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
-use leptos::{RwSignal, Signal, SignalSetter, View};
+use leptos::{MaybeSignal, RwSignal, Signal, SignalGet, SignalSetter, View};
 use std::rc::Rc;
 
 /// Data used for the text area control.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct TextAreaData {
     pub name: String,
-    pub label: Option<String>,
-    pub placeholder: Option<String>,
+    pub label: Option<MaybeSignal<String>>,
+    pub placeholder: Option<MaybeSignal<String>>,
     pub update_event: UpdateEvent,
+    /// Whether the text area is read only.
+    pub readonly: bool,
+    /// Whether a toggle to show a rendered markdown preview of the value
+    /// should be shown.
+    pub preview: bool,
+    /// Whether the browser should check the spelling of the value.
+    ///
+    /// If `None`, the browser's default is used.
+    pub spellcheck: Option<bool>,
+    /// How the browser should automatically capitalize the value.
+    ///
+    /// If `None`, the browser's default is used.
+    pub autocapitalize: Option<Autocapitalize>,
+    /// The number of visible lines of text.
+    ///
+    /// If `None`, the browser's default is used.
+    pub rows: Option<u32>,
+    /// The maximum number of lines the text area grows to fit its value
+    /// before scrolling.
+    ///
+    /// If `None`, the text area does not grow.
+    pub max_rows: Option<u32>,
+}
+
+#[cfg(feature = "markdown")]
+impl TextAreaData {
+    /// Renders the given markdown to html, for the preview.
+    ///
+    /// Any raw html in the markdown is escaped, rather than rendered. Link
+    /// and image destinations are removed unless they are relative, or use
+    /// the http, https or mailto schemes, so links like `javascript:` can't
+    /// run scripts.
+    pub fn preview_html(markdown: &str) -> String {
+        use pulldown_cmark::{Event, Tag};
+
+        fn sanitize(tag: Tag<'_>) -> Tag<'_> {
+            match tag {
+                Tag::Link(kind, dest, title) if !TextAreaData::is_safe_url(&dest) => {
+                    Tag::Link(kind, "".into(), title)
+                }
+                Tag::Image(kind, dest, title) if !TextAreaData::is_safe_url(&dest) => {
+                    Tag::Image(kind, "".into(), title)
+                }
+                tag => tag,
+            }
+        }
+
+        let parser = pulldown_cmark::Parser::new(markdown).map(|event| match event {
+            Event::Html(html) => Event::Text(html),
+            Event::Start(tag) => Event::Start(sanitize(tag)),
+            Event::End(tag) => Event::End(sanitize(tag)),
+            event => event,
+        });
+        let mut html = String::new();
+        pulldown_cmark::html::push_html(&mut html, parser);
+        html
+    }
+
+    /// Checks if the url is relative, or uses one of the allowed schemes.
+    fn is_safe_url(url: &str) -> bool {
+        // browsers ignore whitespace and control characters in the scheme
+        let url = url
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace() && !c.is_control())
+            .collect::<String>()
+            .to_ascii_lowercase();
+        let Some(colon) = url.find(':') else {
+            return true;
+        };
+        // a colon after the path, query or fragment starts is not a scheme
+        if url[..colon].contains(['/', '?', '#']) {
+            return true;
+        }
+        matches!(&url[..colon], "http" | "https" | "mailto")
+    }
 }
 
 impl<FD: FormToolData> ControlData<FD> for TextAreaData {
     type ReturnType = String;
 
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn value_text(&self, value: &Self::ReturnType) -> Option<String> {
+        Some(value.clone())
+    }
+
     fn render_control<FS: FormStyle>(
         fs: &FS,
         _fd: RwSignal<FD>,
@@ -25,8 +108,30 @@ impl<FD: FormToolData> ControlData<FD> for TextAreaData {
         value_getter: Signal<Self::ReturnType>,
         value_setter: SignalSetter<Self::ReturnType>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View {
-        fs.text_area(control, value_getter, value_setter, validation_state)
+        fs.text_area(
+            control,
+            value_getter,
+            value_setter,
+            validation_state,
+            touched,
+        )
+    }
+
+    fn render_display<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: Rc<ControlRenderData<FS, Self>>,
+        value_getter: Signal<Self::ReturnType>,
+    ) -> View {
+        DisplayData::render(
+            fs,
+            &control,
+            &control.data.name,
+            control.data.label.as_ref(),
+            move || value_getter.get(),
+        )
     }
 }
 impl<FD: FormToolData> ValidatedControlData<FD> for TextAreaData {}
@@ -63,19 +168,155 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, TextAreaData, FDT> {
 
     /// Sets the label for the text area.
     pub fn labeled(mut self, label: impl ToString) -> Self {
-        self.data.label = Some(label.to_string());
+        self.data.label = Some(MaybeSignal::Static(label.to_string()));
+        self
+    }
+
+    /// Sets the label for the text area to a signal.
+    ///
+    /// This is useful for labels that change, like localized labels.
+    pub fn labeled_signal(mut self, label: Signal<String>) -> Self {
+        self.data.label = Some(MaybeSignal::Dynamic(label));
         self
     }
 
     /// Sets the placeholder for the text area.
     pub fn placeholder(mut self, placeholder: impl ToString) -> Self {
-        self.data.placeholder = Some(placeholder.to_string());
+        self.data.placeholder = Some(MaybeSignal::Static(placeholder.to_string()));
+        self
+    }
+
+    /// Sets the placeholder for the text area to a signal.
+    pub fn placeholder_signal(mut self, placeholder: Signal<String>) -> Self {
+        self.data.placeholder = Some(MaybeSignal::Dynamic(placeholder));
         self
     }
 
     /// Sets the event that is used to update the form data.
+    ///
+    /// Defaults to [`UpdateEvent::OnChange`].
     pub fn update_on(mut self, event: UpdateEvent) -> Self {
         self.data.update_event = event;
         self
     }
+
+    /// Makes the text area read only.
+    ///
+    /// Unlike a disabled input, a read only input can still be focused and
+    /// its value is still sent with the form.
+    pub fn readonly(mut self) -> Self {
+        self.data.readonly = true;
+        self
+    }
+
+    /// Sets whether the browser should check the spelling of the text area.
+    ///
+    /// This is useful to turn off for things that aren't words, like
+    /// usernames or codes.
+    pub fn spellcheck(mut self, spellcheck: bool) -> Self {
+        self.data.spellcheck = Some(spellcheck);
+        self
+    }
+
+    /// Sets how the browser should automatically capitalize what is typed
+    /// into the text area.
+    ///
+    /// Mobile browsers capitalize the first letter by default, which can
+    /// corrupt identifiers and codes. See [`Autocapitalize`].
+    pub fn autocapitalize(mut self, autocapitalize: Autocapitalize) -> Self {
+        self.data.autocapitalize = Some(autocapitalize);
+        self
+    }
+
+    /// Sets the number of visible lines of text in the text area.
+    ///
+    /// When used with [`max_rows`](Self::max_rows), this is the size the
+    /// text area starts at.
+    pub fn rows(mut self, rows: u32) -> Self {
+        self.data.rows = Some(rows);
+        self
+    }
+
+    /// Makes the text area grow to fit its value, up to `max_rows` lines,
+    /// and then scroll.
+    ///
+    /// This keeps long pasted content from pushing the rest of the form
+    /// off screen. The text area never shrinks below its
+    /// [`rows`](Self::rows).
+    pub fn max_rows(mut self, max_rows: u32) -> Self {
+        self.data.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Shows a toggle between editing the text area and a rendered markdown
+    /// preview of its value.
+    ///
+    /// The value stored is still the raw markdown.
+    #[cfg(feature = "markdown")]
+    pub fn with_preview(mut self) -> Self {
+        self.data.preview = true;
+        self
+    }
+}
+
+#[cfg(all(test, feature = "markdown"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preview_html_escapes_raw_html() {
+        let html = TextAreaData::preview_html("<script>alert(1)</script>\n");
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+
+        let html = TextAreaData::preview_html("hi <img src=x onerror=alert(1)> there");
+        assert!(!html.contains("<img"));
+        assert!(html.contains("&lt;img src=x onerror=alert(1)&gt;"));
+    }
+
+    #[test]
+    fn preview_html_strips_unsafe_urls() {
+        let unsafe_urls = [
+            "javascript:alert(1)",
+            "JaVaScRiPt:alert(1)",
+            "<java\tscript:alert(1)>",
+            "&#106;avascript:alert(1)",
+            "data:text/html;base64,PHNjcmlwdD4=",
+        ];
+        for url in unsafe_urls {
+            let html = TextAreaData::preview_html(&format!("[link]({})", url));
+            assert_eq!(html, "<p><a href=\"\">link</a></p>\n", "{}", url);
+            let html = TextAreaData::preview_html(&format!("![image]({})", url));
+            assert_eq!(html, "<p><img src=\"\" alt=\"image\" /></p>\n", "{}", url);
+        }
+    }
+
+    #[test]
+    fn preview_html_keeps_safe_urls() {
+        let safe_urls = [
+            "/about",
+            "docs/page.md",
+            "#section",
+            "http://example.com",
+            "https://example.com/a?b=c:d",
+            "mailto:someone@example.com",
+        ];
+        for url in safe_urls {
+            let html = TextAreaData::preview_html(&format!("[link]({})", url));
+            assert_eq!(html, format!("<p><a href=\"{}\">link</a></p>\n", url));
+            let html = TextAreaData::preview_html(&format!("![image]({})", url));
+            assert_eq!(
+                html,
+                format!("<p><img src=\"{}\" alt=\"image\" /></p>\n", url)
+            );
+        }
+    }
+
+    #[test]
+    fn is_safe_url_ignores_whitespace_and_case_in_the_scheme() {
+        assert!(!TextAreaData::is_safe_url("java\tscript:alert(1)"));
+        assert!(!TextAreaData::is_safe_url(" JAVASCRIPT:alert(1)"));
+        assert!(!TextAreaData::is_safe_url("java\u{0}script:alert(1)"));
+        assert!(TextAreaData::is_safe_url("HTTPS://example.com"));
+    }
 }
@@ -1,19 +1,31 @@
 use super::{
-    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, UpdateEvent,
-    ValidatedControlData, ValidationState,
+    display::DisplayData, Autocapitalize, BuilderCxFn, BuilderFn, ControlBuilder, ControlData,
+    ControlRenderData, UpdateEvent, ValidatedControlData, ValidationState,
 };
 use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
-use leptos::{RwSignal, Signal, SignalSetter, View};
+use leptos::{MaybeSignal, RwSignal, Signal, SignalGet, SignalSetter, View};
 use std::rc::Rc;
 
 /// Data used for the text input control.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TextInputData {
     pub name: String,
-    pub label: Option<String>,
-    pub placeholder: Option<String>,
+    pub label: Option<MaybeSignal<String>>,
+    pub placeholder: Option<MaybeSignal<String>>,
     pub input_type: &'static str,
     pub update_event: UpdateEvent,
+    /// Whether a button to clear the input should be shown.
+    pub clearable: bool,
+    /// Whether the input is read only.
+    pub readonly: bool,
+    /// Whether the browser should check the spelling of the value.
+    ///
+    /// If `None`, the browser's default is used.
+    pub spellcheck: Option<bool>,
+    /// How the browser should automatically capitalize the value.
+    ///
+    /// If `None`, the browser's default is used.
+    pub autocapitalize: Option<Autocapitalize>,
 }
 
 impl Default for TextInputData {
@@ -24,6 +36,10 @@ impl Default for TextInputData {
             label: None,
             input_type: "input",
             update_event: UpdateEvent::default(),
+            clearable: false,
+            readonly: false,
+            spellcheck: None,
+            autocapitalize: None,
         }
     }
 }
@@ -31,6 +47,15 @@ impl Default for TextInputData {
 impl<FD: FormToolData> ControlData<FD> for TextInputData {
     type ReturnType = String;
 
+    fn name(&self) -> Option<&str> {
+        Some(&self.name)
+    }
+
+    fn value_text(&self, value: &Self::ReturnType) -> Option<String> {
+        // don't show passwords
+        (self.input_type != "password").then(|| value.clone())
+    }
+
     fn render_control<FS: FormStyle>(
         fs: &FS,
         _fd: RwSignal<FD>,
@@ -38,8 +63,39 @@ impl<FD: FormToolData> ControlData<FD> for TextInputData {
         value_getter: Signal<Self::ReturnType>,
         value_setter: SignalSetter<Self::ReturnType>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
+    ) -> View {
+        fs.text_input(
+            control,
+            value_getter,
+            value_setter,
+            validation_state,
+            touched,
+        )
+    }
+
+    fn render_display<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: Rc<ControlRenderData<FS, Self>>,
+        value_getter: Signal<Self::ReturnType>,
     ) -> View {
-        fs.text_input(control, value_getter, value_setter, validation_state)
+        let password = control.data.input_type == "password";
+        let value = move || {
+            let value = value_getter.get();
+            // don't show passwords in plain text, or how long they are
+            match password && !value.is_empty() {
+                true => String::from("••••••••"),
+                false => value,
+            }
+        };
+        DisplayData::render(
+            fs,
+            &control,
+            &control.data.name,
+            control.data.label.as_ref(),
+            value,
+        )
     }
 }
 impl<FD: FormToolData> ValidatedControlData<FD> for TextInputData {}
@@ -76,13 +132,27 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, TextInputData, FDT> {
 
     /// Sets the label for the text input.
     pub fn labeled(mut self, label: impl ToString) -> Self {
-        self.data.label = Some(label.to_string());
+        self.data.label = Some(MaybeSignal::Static(label.to_string()));
+        self
+    }
+
+    /// Sets the label for the text input to a signal.
+    ///
+    /// This is useful for labels that change, like localized labels.
+    pub fn labeled_signal(mut self, label: Signal<String>) -> Self {
+        self.data.label = Some(MaybeSignal::Dynamic(label));
         self
     }
 
     /// Sets the placeholder for the text input.
     pub fn placeholder(mut self, placeholder: impl ToString) -> Self {
-        self.data.placeholder = Some(placeholder.to_string());
+        self.data.placeholder = Some(MaybeSignal::Static(placeholder.to_string()));
+        self
+    }
+
+    /// Sets the placeholder for the text input to a signal.
+    pub fn placeholder_signal(mut self, placeholder: Signal<String>) -> Self {
+        self.data.placeholder = Some(MaybeSignal::Dynamic(placeholder));
         self
     }
 
@@ -98,6 +168,15 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, TextInputData, FDT> {
         self
     }
 
+    /// Sets the text input to be the "url" type.
+    ///
+    /// Browsers only loosely validate urls, so this is usually paired with
+    /// [`parse_url`](ControlBuilder::parse_url)().
+    pub fn url(mut self) -> Self {
+        self.data.input_type = "url";
+        self
+    }
+
     /// Sets the text input to be the specified type.
     pub fn input_type(mut self, input_type: &'static str) -> Self {
         self.data.input_type = input_type;
@@ -105,8 +184,86 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, TextInputData, FDT> {
     }
 
     /// Sets the event that is used to update the form data.
+    ///
+    /// Defaults to [`UpdateEvent::OnChange`].
     pub fn update_on(mut self, event: UpdateEvent) -> Self {
         self.data.update_event = event;
         self
     }
+
+    /// Shows a button to clear the text input when it is not empty.
+    pub fn clearable(mut self) -> Self {
+        self.data.clearable = true;
+        self
+    }
+
+    /// Makes the text input read only.
+    ///
+    /// Unlike a disabled input, a read only input can still be focused and
+    /// its value is still sent with the form.
+    pub fn readonly(mut self) -> Self {
+        self.data.readonly = true;
+        self
+    }
+
+    /// Sets whether the browser should check the spelling of the text input.
+    ///
+    /// This is useful to turn off for things that aren't words, like
+    /// usernames or codes.
+    pub fn spellcheck(mut self, spellcheck: bool) -> Self {
+        self.data.spellcheck = Some(spellcheck);
+        self
+    }
+
+    /// Sets how the browser should automatically capitalize what is typed
+    /// into the text input.
+    ///
+    /// Mobile browsers capitalize the first letter by default, which can
+    /// corrupt identifiers and codes. See [`Autocapitalize`].
+    pub fn autocapitalize(mut self, autocapitalize: Autocapitalize) -> Self {
+        self.data.autocapitalize = Some(autocapitalize);
+        self
+    }
+}
+
+impl<FD: FormToolData> ControlBuilder<FD, TextInputData, String> {
+    /// Sets the parse functions to normalize the input into an absolute url.
+    ///
+    /// The input is trimmed, and `https://` is prepended if the user left
+    /// out the scheme. An empty input is stored as an empty string.
+    ///
+    /// With the `url` feature enabled, the url is also validated and stored
+    /// in its canonical form, and an invalid url is reported as a parse
+    /// error.
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// types in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn parse_url(mut self) -> Self {
+        self.parse_fn = Some(Box::new(|control_return_value| {
+            let value = control_return_value.trim();
+            if value.is_empty() {
+                return Ok(String::new());
+            }
+            if value.chars().any(char::is_whitespace) {
+                return Err(String::from("Url cannot contain spaces"));
+            }
+
+            let value = if value.contains("://") {
+                value.to_string()
+            } else {
+                format!("https://{}", value)
+            };
+
+            #[cfg(feature = "url")]
+            let value = url::Url::parse(&value)
+                .map_err(|e| format!("Invalid url: {}", e))?
+                .to_string();
+
+            Ok(value)
+        }));
+        self.unparse_fn = Some(Box::new(|field| field));
+        self.custom_parse_msg = false;
+        self
+    }
 }
@@ -1,32 +1,302 @@
-use crate::{controls::ValidationFn, form_builder::FormBuilder, styles::FormStyle};
+use crate::{
+    controls::{
+        change_summary::FieldChange, ControlState, FieldsValidationFn, SimulateFn, SimulateState,
+        ValidationFn, ValidationState,
+    },
+    form_builder::FormBuilder,
+    styles::FormStyle,
+};
 use ev::SubmitEvent;
 use leptos::{
-    server_fn::{client::Client, codec::PostUrl, request::ClientReq, ServerFn},
+    server_fn::{
+        client::Client,
+        codec::{MultipartData, MultipartFormData, PostUrl},
+        request::ClientReq,
+        ServerFn,
+    },
     *,
 };
 use serde::de::DeserializeOwned;
-use std::rc::Rc;
-use web_sys::FormData;
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    rc::Rc,
+};
+use web_sys::{wasm_bindgen::JsCast, FormData};
+
+/// How the controls of a form are rendered.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum FormMode {
+    /// The controls are rendered as inputs that the user can edit.
+    #[default]
+    Editable,
+    /// The controls are rendered as read only text showing their values.
+    ///
+    /// This allows the same form to be used for both editing and viewing
+    /// the data. Vanity controls render the same in both modes.
+    Display,
+}
+
+/// Where the error messages of a form are shown.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum ErrorDisplay {
+    /// Each control shows its own error message.
+    #[default]
+    Inline,
+    /// The error messages are shown together in a summary once submitting
+    /// fails, instead of with each control.
+    ///
+    /// This is useful for dense forms without room for inline messages.
+    Summary,
+    /// The error messages are shown with each control, and in a summary
+    /// once submitting fails.
+    Both,
+}
+
+impl ErrorDisplay {
+    /// Whether the controls should show their own error messages.
+    pub fn inline(self) -> bool {
+        matches!(self, ErrorDisplay::Inline | ErrorDisplay::Both)
+    }
+
+    /// Whether the form should show a summary of the error messages.
+    pub fn summary(self) -> bool {
+        matches!(self, ErrorDisplay::Summary | ErrorDisplay::Both)
+    }
+}
+
+/// The HTTP method that a plain form is submitted with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum FormMethod {
+    /// Sends the form data in the url's query string.
+    ///
+    /// This is useful for search or filter forms.
+    #[default]
+    Get,
+    /// Sends the form data in the request body.
+    Post,
+}
+
+impl FormMethod {
+    /// Gets the value of the form's `method` attribute.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FormMethod::Get => "get",
+            FormMethod::Post => "post",
+        }
+    }
+}
 
 /// A type that can be used to validate the form data.
 ///
 /// This can be useful to use the same validation logic on the front
 /// end and backend without duplicating the logic.
 pub struct FormValidator<FD> {
-    pub(crate) validations: Vec<Rc<dyn ValidationFn<FD>>>,
+    pub(crate) validations: Vec<Rc<dyn FieldsValidationFn<FD>>>,
 }
 
 impl<FD: FormToolData> FormValidator<FD> {
+    /// Creates a new [`FormValidator`] with no validations.
+    ///
+    /// Validations can then be added with
+    /// [`with_validation`](Self::with_validation). This allows reusing
+    /// validation logic without building a form, like for validating data
+    /// in server code.
+    pub fn new() -> Self {
+        FormValidator {
+            validations: Vec::new(),
+        }
+    }
+
+    /// Adds a validation function to the validator.
+    ///
+    /// The function should return `Err` with a message describing the
+    /// problem if the data is invalid.
+    ///
+    /// The error is not tied to a field, so it is keyed by an empty string
+    /// in [`validate_to_map`](Self::validate_to_map).
+    pub fn with_validation(self, validation: impl ValidationFn<FD>) -> Self {
+        self.with_field_validation("", validation)
+    }
+
+    /// Adds a validation function for the field with the given name to the
+    /// validator.
+    ///
+    /// The function should return `Err` with a message describing the
+    /// problem if the data is invalid.
+    pub fn with_field_validation(
+        mut self,
+        name: impl ToString,
+        validation: impl ValidationFn<FD>,
+    ) -> Self {
+        let name = name.to_string();
+        self.validations.push(Rc::new(move |fd: &FD| {
+            validation(fd).map_err(|msg| vec![(name.clone(), msg)])
+        }));
+        self
+    }
+
     /// Validates the given form data.
     ///
     /// This runs all the validation functions for all the fields
     /// in the form. The first falure to occur (if any) will be returned.
     pub fn validate(&self, form_data: &FD) -> Result<(), String> {
         for v in self.validations.iter() {
-            (*v)(form_data)?;
+            (*v)(form_data).map_err(|errors| {
+                errors
+                    .into_iter()
+                    .map(|(_, msg)| msg)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            })?;
         }
         Ok(())
     }
+
+    /// Validates the given form data, returning the error message of each
+    /// field that fails.
+    ///
+    /// Unlike [`validate`](Self::validate), this runs all the validations,
+    /// and the errors are keyed by the name of the field. If a field fails
+    /// more than one validation, the first message is kept. Errors that are
+    /// not tied to a field are keyed by an empty string.
+    ///
+    /// The map serializes to a `{ field: message }` object, which makes it
+    /// useful for the error responses of an API.
+    pub fn validate_to_map(&self, form_data: &FD) -> Result<(), HashMap<String, String>> {
+        let mut errors = HashMap::new();
+        for v in self.validations.iter() {
+            if let Err(field_errors) = (*v)(form_data) {
+                for (name, msg) in field_errors {
+                    errors.entry(name).or_insert(msg);
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl<FD: FormToolData> Default for FormValidator<FD> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Creates a signal of whether none of the controls have an error.
+pub(crate) fn validity(control_states: StoredValue<Vec<ControlState>>) -> Signal<bool> {
+    Signal::derive(move || {
+        control_states.with_value(|states| {
+            states
+                .iter()
+                .all(|state| !state.validation_state.get().is_err())
+        })
+    })
+}
+
+/// Creates a signal of the fraction of validated controls that currently
+/// pass their validation.
+pub(crate) fn completion(control_states: StoredValue<Vec<ControlState>>) -> Signal<f32> {
+    Signal::derive(move || {
+        control_states.with_value(|states| {
+            let (complete, total) = states
+                .iter()
+                .filter_map(|state| state.complete.get())
+                .fold((0, 0), |(complete, total), passes| {
+                    (complete + passes as u32, total + 1)
+                });
+            if total == 0 {
+                1.0
+            } else {
+                complete as f32 / total as f32
+            }
+        })
+    })
+}
+
+/// Creates a signal of the named controls whose values have changed from
+/// their initial values.
+pub(crate) fn changes(control_states: StoredValue<Vec<ControlState>>) -> Signal<Vec<FieldChange>> {
+    Signal::derive(move || {
+        control_states.with_value(|states| {
+            states
+                .iter()
+                .filter_map(|state| {
+                    let (old, new) = state.change.get()?;
+                    Some(FieldChange {
+                        name: state.name.clone(),
+                        old,
+                        new,
+                    })
+                })
+                .collect()
+        })
+    })
+}
+
+/// A type that can be used to drive the controls of a form without
+/// rendering it.
+///
+/// This runs the same parsing and validation that happens when the user
+/// changes a control, which makes it useful for unit testing a form's
+/// logic without a browser.
+pub struct FormSimulator<FD> {
+    pub(crate) simulators: Vec<(String, Rc<dyn SimulateFn<FD>>)>,
+    pub(crate) state: SimulateState<FD>,
+}
+
+impl<FD: FormToolData> FormSimulator<FD> {
+    /// Simulates the user entering `value` into the control with the given
+    /// name.
+    ///
+    /// The value is parsed and set on the form data, then the control's
+    /// validation is run. The updated form data is returned along with the
+    /// control's resulting [`ValidationState`]. If parsing fails, the form
+    /// data is returned unchanged.
+    ///
+    /// This follows what the rendered form does:
+    /// - Parse errors use the form's
+    ///   [`default_parse_error`](FormBuilder::default_parse_error) message,
+    ///   unless the control has its own.
+    /// - If the control passes its validation, errors on it from the
+    ///   validations of the whole form, like
+    ///   [`validate_fields`](FormBuilder::validate_fields), are returned.
+    ///   These are skipped while the
+    ///   [`validation_gate`](FormBuilder::validation_gate) fails.
+    /// - A control that is hidden by its `show_when` condition, or its
+    ///   group's, can't be changed, so the form data is returned unchanged
+    ///   with [`ValidationState::Passed`].
+    ///
+    /// The value must be of the control's return type, which is a
+    /// [`String`] for most controls, and a [`bool`] for checkboxes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no control with the given name, or if the value is
+    /// of the wrong type.
+    pub fn simulate_input<T: 'static>(
+        &self,
+        mut form_data: FD,
+        name: &str,
+        value: T,
+    ) -> (FD, ValidationState) {
+        let (_, simulate_fn) = self
+            .simulators
+            .iter()
+            .find(|(control_name, _)| control_name == name)
+            .unwrap_or_else(|| panic!("No control named \"{}\"", name));
+        // use a runtime of its own, so that this works without one, like in
+        // unit tests, and doesn't leave anything behind in the caller's
+        let prev_runtime = current_runtime();
+        let runtime = create_runtime();
+        let validation_state = simulate_fn(&mut form_data, Box::new(value), &self.state);
+        runtime.dispose();
+        set_current_runtime(prev_runtime);
+        (form_data, validation_state)
+    }
 }
 
 /// A constructed, rendered form object.
@@ -38,8 +308,12 @@ pub struct Form<FD: FormToolData> {
     /// The form data signal.
     pub fd: RwSignal<FD>,
     /// The list of validations
-    pub(crate) validations: Vec<Rc<dyn ValidationFn<FD>>>,
+    pub(crate) validations: Vec<Rc<dyn FieldsValidationFn<FD>>>,
     pub(crate) view: View,
+    /// The state of all the named controls in the form.
+    pub(crate) control_states: StoredValue<Vec<ControlState>>,
+    /// A reference to the rendered `<form>` element.
+    pub(crate) node_ref: NodeRef<html::Form>,
 }
 
 impl<FD: FormToolData> Form<FD> {
@@ -50,10 +324,179 @@ impl<FD: FormToolData> Form<FD> {
         }
     }
 
+    /// Gets a clone of the current form data.
+    ///
+    /// This does not track the form data signal.
+    pub fn data(&self) -> FD {
+        self.fd.get_untracked()
+    }
+
+    /// Runs the given function with a reference to the current form data,
+    /// returning the result.
+    ///
+    /// This does not track the form data signal.
+    pub fn with_data<R>(&self, f: impl FnOnce(&FD) -> R) -> R {
+        self.fd.with_untracked(f)
+    }
+
     /// Validates the [`FormToolData`], returning the result.
     pub fn validate(&self) -> Result<(), String> {
         let validator = self.validator();
-        validator.validate(&self.fd.get_untracked())
+        self.with_data(|fd| validator.validate(fd))
+    }
+
+    /// Gets the current errors of all the fields, keyed by the control's
+    /// name.
+    ///
+    /// Only fields that currently have a parse or validation error are
+    /// present in the map.
+    pub fn field_errors(&self) -> Signal<HashMap<String, String>> {
+        let control_states = self.control_states;
+        Signal::derive(move || {
+            control_states.with_value(|states| {
+                states
+                    .iter()
+                    .filter_map(|state| {
+                        state
+                            .validation_state
+                            .get()
+                            .take_msg()
+                            .map(|msg| (state.name.clone(), msg))
+                    })
+                    .collect()
+            })
+        })
+    }
+
+    /// Gets a signal of whether the form is valid.
+    ///
+    /// This is false while any of the controls has a parse or validation
+    /// error.
+    pub fn is_valid(&self) -> Signal<bool> {
+        validity(self.control_states)
+    }
+
+    /// Gets a signal of how complete the form is, from 0.0 to 1.0.
+    ///
+    /// This is the fraction of the named controls with a validation that
+    /// currently pass it. Unlike [`is_valid`](Self::is_valid)(), this is
+    /// based on the current form data, so untouched controls count as
+    /// incomplete until they are filled in. Hidden controls are not
+    /// counted. If there are no such controls, the form is complete.
+    pub fn completion(&self) -> Signal<f32> {
+        completion(self.control_states)
+    }
+
+    /// Gets a signal of the fields that have changed from their initial
+    /// values, with their old and new values as text.
+    ///
+    /// Only controls that can show their value as text are included; see
+    /// [`ControlData::value_text`](crate::controls::ControlData::value_text).
+    /// A field that is changed back to its initial value is no longer
+    /// listed.
+    pub fn changes(&self) -> Signal<Vec<FieldChange>> {
+        changes(self.control_states)
+    }
+
+    /// Gets the names of all the fields that the user has interacted with.
+    ///
+    /// A field is touched once the user changes its value or it loses focus.
+    pub fn touched_fields(&self) -> Signal<HashSet<String>> {
+        let control_states = self.control_states;
+        Signal::derive(move || {
+            control_states.with_value(|states| {
+                states
+                    .iter()
+                    .filter(|state| state.touched.get())
+                    .map(|state| state.name.clone())
+                    .collect()
+            })
+        })
+    }
+
+    /// Gets the checklist of the field with the given name.
+    ///
+    /// This is the name of each check added with
+    /// [`check`](crate::controls::ControlBuilder::check)(), along with
+    /// whether the check currently passes. If there is no field with that
+    /// name, the list is empty.
+    pub fn checklist(&self, name: &str) -> Signal<Vec<(String, bool)>> {
+        let control_states = self.control_states;
+        let name = name.to_string();
+        Signal::derive(move || {
+            control_states.with_value(|states| {
+                states
+                    .iter()
+                    .find(|state| state.name == name)
+                    .map(|state| state.checklist.get())
+                    .unwrap_or_default()
+            })
+        })
+    }
+
+    /// Gets a signal of what is currently in the control with the given
+    /// name, even if it failed to parse.
+    ///
+    /// While a control has a parse error, the form data keeps the last
+    /// value that parsed, so this is the only way to get what the user
+    /// actually entered, like for logging parse issues.
+    ///
+    /// `T` must be the control's return type, which is a [`String`] for
+    /// most controls, and a [`bool`] for checkboxes. The signal is `None`
+    /// if there is no control with that name, or `T` is the wrong type.
+    pub fn raw_value<T: Clone + 'static>(&self, name: &str) -> Signal<Option<T>> {
+        let control_states = self.control_states;
+        let name = name.to_string();
+        Signal::derive(move || {
+            let raw_value = control_states.with_value(|states| {
+                states
+                    .iter()
+                    .find(|state| state.name == name)
+                    .and_then(|state| state.raw_value.downcast_ref::<Signal<T>>().copied())
+            });
+            raw_value.map(|raw_value| raw_value.get())
+        })
+    }
+
+    /// Focuses the input of the control with the given name.
+    ///
+    /// This is useful for "jump to this field" links, like in a list of
+    /// errors. For controls with several inputs, like radio buttons, the
+    /// checked input is focused, or the first one if none are checked.
+    /// Returns false if there is no rendered control with that name, like
+    /// before the form is mounted in the browser.
+    pub fn focus_field(&self, name: &str) -> bool {
+        let element = self.control_states.with_value(|states| {
+            states
+                .iter()
+                .find(|state| state.name == name)
+                .and_then(|state| state.input_ref.get_untracked())
+        });
+        let Some(element) = element else {
+            return false;
+        };
+        let input = element
+            .query_selector("input:checked")
+            .ok()
+            .flatten()
+            .or_else(|| element.query_selector("input").ok().flatten());
+        let result = match input.and_then(|input| input.dyn_into::<web_sys::HtmlElement>().ok()) {
+            Some(input) => input.focus(),
+            None => element.focus(),
+        };
+        result.is_ok()
+    }
+
+    /// Gets a reference to the rendered `<form>` element.
+    ///
+    /// This can be used to call methods on the form element, like
+    /// `report_validity()` or `request_submit()`, or to interact with other
+    /// js libraries. The reference is only populated once the form is
+    /// mounted in the browser. Forms built with
+    /// [`get_form_controls`](FormToolData::get_form_controls) don't render
+    /// a `<form>` element, so the reference is never populated.
+    pub fn node_ref(&self) -> NodeRef<html::Form> {
+        self.node_ref
     }
 
     /// Gets the view associated with this [`Form`].
@@ -61,6 +504,60 @@ impl<FD: FormToolData> Form<FD> {
         self.view.clone()
     }
 
+    /// Renders the form inside of a modal `<dialog>` element.
+    ///
+    /// The dialog is shown as a modal when `open` is true, which keeps focus
+    /// within the dialog. Pressing Escape closes the dialog and calls
+    /// `on_cancel` with the form data signal, which can be used to reset the
+    /// form.
+    pub fn dialog(&self, open: RwSignal<bool>, on_cancel: impl Fn(RwSignal<FD>) + 'static) -> View {
+        let dialog_ref = create_node_ref::<html::Dialog>();
+        create_effect(move |_| {
+            let open = open.get();
+            let Some(dialog) = dialog_ref.get() else {
+                return;
+            };
+            if open && !dialog.open() {
+                let _ = dialog.show_modal();
+            } else if !open && dialog.open() {
+                dialog.close();
+            }
+        });
+
+        let fd = self.fd;
+        view! {
+            <dialog node_ref=dialog_ref class="form_dialog" on:close=move |_| open.set(false)>
+                {self.view()}
+            </dialog>
+        }
+        .on(ev::Custom::<ev::Event>::new("cancel"), move |_| {
+            on_cancel(fd)
+        })
+        .into_view()
+    }
+
+    /// Updates the form data whenever `data` changes, as long as the user
+    /// has not edited the form since the last update.
+    pub(crate) fn sync_from(&self, data: Signal<FD>)
+    where
+        FD: PartialEq,
+    {
+        let fd = self.fd;
+        let last_synced = store_value(data.get_untracked());
+        create_effect(move |_| {
+            let new_data = data.get();
+            let (unchanged, edited) = last_synced.with_value(|last_synced| {
+                let edited = fd.with_untracked(|fd| fd != last_synced);
+                (new_data == *last_synced, edited)
+            });
+            if unchanged || edited {
+                return;
+            }
+            last_synced.set_value(new_data.clone());
+            fd.set(new_data);
+        });
+    }
+
     /// Splits this [`Form`] into it's parts.
     pub fn to_parts(self) -> (RwSignal<FD>, FormValidator<FD>, View) {
         (
@@ -79,6 +576,56 @@ impl<FD: FormToolData> IntoView for Form<FD> {
     }
 }
 
+/// An action that sends the [`FormData`] of a multipart form to the server
+/// function `ServFn`.
+///
+/// Server functions that use the [`MultipartFormData`] encoding can't be
+/// cloned, so they can't be used with leptos' `create_server_action`. This
+/// wraps an action that dispatches the [`FormData`] instead, for use with
+/// [`get_multipart_form`](FormToolData::get_multipart_form).
+pub struct MultipartAction<ServFn>
+where
+    ServFn: ServerFn,
+    ServFn::Output: 'static,
+    ServFn::Error: 'static,
+{
+    action: Action<FormData, Result<ServFn::Output, ServerFnError<ServFn::Error>>>,
+}
+
+impl<ServFn> MultipartAction<ServFn>
+where
+    ServFn: ServerFn<InputEncoding = MultipartFormData> + From<MultipartData> + 'static,
+{
+    /// Creates a new [`MultipartAction`] that calls `ServFn`.
+    pub fn new() -> Self {
+        let action = create_action(|data: &FormData| {
+            ServFn::from(MultipartData::from(data.clone())).run_on_client()
+        });
+        MultipartAction { action }
+    }
+}
+
+impl<ServFn> Default for MultipartAction<ServFn>
+where
+    ServFn: ServerFn<InputEncoding = MultipartFormData> + From<MultipartData> + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<ServFn> MultipartAction<ServFn>
+where
+    ServFn: ServerFn,
+    ServFn::Output: 'static,
+    ServFn::Error: 'static,
+{
+    /// Gets the underlying action, to read its pending state or result.
+    pub fn action(&self) -> Action<FormData, Result<ServFn::Output, ServerFnError<ServFn::Error>>> {
+        self.action
+    }
+}
+
 /// A trait allowing a form to be built around its containing data.
 ///
 /// This trait defines a function that can be used to build all the data
@@ -120,6 +667,7 @@ pub trait FormToolData: Clone + 'static {
     ///
     /// For the other ways to construct a [`Form`], see:
     /// - [`get_action_form`](Self::get_action_form)
+    /// - [`get_multipart_form`](Self::get_multipart_form)
     /// - [`get_plain_form`](Self::get_plain_form)
     /// - [`get_form_controls`](Self::get_form_controls)
     fn get_form<ServFn, F: Fn(SubmitEvent, RwSignal<Self>) + 'static>(
@@ -140,6 +688,126 @@ pub trait FormToolData: Clone + 'static {
         builder.build_form(action, on_submit, self, style)
     }
 
+    /// Constructs a [`Form`] for this [`FormToolData`] type, with an async
+    /// submit handler.
+    ///
+    /// This is like [`get_form`](Self::get_form), but the future returned
+    /// by `on_submit` is awaited before the server function is called. This
+    /// allows doing async work before submitting, like uploading an image
+    /// and putting its url in the form data. The form is still validated
+    /// before `on_submit` is called.
+    fn get_form_async<ServFn, F, Fut>(
+        self,
+        action: Action<ServFn, Result<ServFn::Output, ServerFnError<ServFn::Error>>>,
+        on_submit: F,
+        style: Self::Style,
+        context: Self::Context,
+    ) -> Form<Self>
+    where
+        F: Fn(SubmitEvent, RwSignal<Self>) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+        ServFn: DeserializeOwned + ServerFn<InputEncoding = PostUrl> + 'static,
+        <<ServFn::Client as Client<ServFn::Error>>::Request as ClientReq<ServFn::Error>>::FormData:
+            From<FormData>,
+        ServFn: From<Self>,
+    {
+        let builder = FormBuilder::new(context);
+        let builder = Self::build_form(builder);
+        builder.build_form_async(action, on_submit, self, style)
+    }
+
+    /// Constructs a [`Form`] for this [`FormToolData`] type, with its data
+    /// kept in sync with the given signal.
+    ///
+    /// This is like [`get_form`](Self::get_form), but the initial data comes
+    /// from `data`, and the form is updated whenever `data` changes. This is
+    /// useful for forms that mirror server state that can change, like the
+    /// result of a resource that refetches.
+    ///
+    /// To avoid clobbering the user's edits, updates are only applied while
+    /// the form data is unchanged from the last value of `data`.
+    fn get_form_from_signal<ServFn, F: Fn(SubmitEvent, RwSignal<Self>) + 'static>(
+        data: Signal<Self>,
+        action: Action<ServFn, Result<ServFn::Output, ServerFnError<ServFn::Error>>>,
+        on_submit: F,
+        style: Self::Style,
+        context: Self::Context,
+    ) -> Form<Self>
+    where
+        Self: PartialEq,
+        ServFn: DeserializeOwned + ServerFn<InputEncoding = PostUrl> + 'static,
+        <<ServFn::Client as Client<ServFn::Error>>::Request as ClientReq<ServFn::Error>>::FormData:
+            From<FormData>,
+        ServFn: From<Self>,
+    {
+        let form = data
+            .get_untracked()
+            .get_form(action, on_submit, style, context);
+        form.sync_from(data);
+        form
+    }
+
+    /// Constructs a [`Form`] for this [`FormToolData`] type.
+    ///
+    /// This renders the form with the `multipart/form-data` encoding, which
+    /// is needed for forms that upload files. When submitted, the form's
+    /// [`FormData`] is sent to a server function that uses the
+    /// [`MultipartFormData`] encoding, with a [`MultipartAction`]. All the
+    /// other fields are sent alongside the files.
+    ///
+    /// ```no_run
+    /// # use leptos::{server_fn::codec::{MultipartData, MultipartFormData}, *};
+    /// # use leptos_form_tool::{styles::GridFormStyle, *};
+    /// #[server(input = MultipartFormData)]
+    /// async fn upload(data: MultipartData) -> Result<(), ServerFnError> {
+    ///     // read the fields and files with `data.into_inner()`
+    ///     Ok(())
+    /// }
+    ///
+    /// #[derive(Clone, Default)]
+    /// struct Document {
+    ///     title: String,
+    /// }
+    ///
+    /// impl FormToolData for Document {
+    ///     type Style = GridFormStyle;
+    ///     type Context = ();
+    ///
+    ///     fn build_form(fb: FormBuilder<Self>) -> FormBuilder<Self> {
+    ///         fb.text_input(|t| {
+    ///             t.named("title")
+    ///                 .parse_string()
+    ///                 .getter(|fd| fd.title.clone())
+    ///                 .setter(|fd, title| fd.title = title)
+    ///         })
+    ///         .submit(|s| s.text("Upload"))
+    ///     }
+    /// }
+    ///
+    /// let action = MultipartAction::<Upload>::new();
+    /// let form = Document::default().get_multipart_form(action, |_, _| {}, GridFormStyle, ());
+    /// ```
+    ///
+    /// For the other ways to construct a [`Form`], see:
+    /// - [`get_form`](Self::get_form)
+    /// - [`get_action_form`](Self::get_action_form)
+    /// - [`get_plain_form`](Self::get_plain_form)
+    /// - [`get_form_controls`](Self::get_form_controls)
+    fn get_multipart_form<ServFn, F: Fn(SubmitEvent, RwSignal<Self>) + 'static>(
+        self,
+        action: MultipartAction<ServFn>,
+        on_submit: F,
+        style: Self::Style,
+        context: Self::Context,
+    ) -> Form<Self>
+    where
+        ServFn: ServerFn<InputEncoding = MultipartFormData> + From<MultipartData> + 'static,
+    {
+        let builder = FormBuilder::new(context);
+        let builder = Self::build_form(builder);
+        builder.build_multipart_form(action, on_submit, self, style)
+    }
+
     /// Constructs a [`Form`] for this [`FormToolData`] type.
     ///
     /// This renders the form as a the leptos_router
@@ -148,6 +816,7 @@ pub trait FormToolData: Clone + 'static {
     ///
     /// For the other ways to construct a [`Form`], see:
     /// - [`get_form`](Self::get_form)
+    /// - [`get_multipart_form`](Self::get_multipart_form)
     /// - [`get_plain_form`](Self::get_plain_form)
     /// - [`get_form_controls`](Self::get_form_controls)
     fn get_action_form<ServFn, F: Fn(SubmitEvent, RwSignal<Self>) + 'static>(
@@ -176,6 +845,7 @@ pub trait FormToolData: Clone + 'static {
     /// For the other ways to construct a [`Form`], see:
     /// - [`get_form`](Self::get_form)
     /// - [`get_action_form`](Self::get_action_form)
+    /// - [`get_multipart_form`](Self::get_multipart_form)
     /// - [`get_form_controls`](Self::get_form_controls)
     fn get_plain_form<F: Fn(SubmitEvent, RwSignal<Self>) + 'static>(
         self,
@@ -198,6 +868,7 @@ pub trait FormToolData: Clone + 'static {
     /// For the other ways to construct a [`Form`], see:
     /// - [`get_form`](Self::get_form)
     /// - [`get_action_form`](Self::get_action_form)
+    /// - [`get_multipart_form`](Self::get_multipart_form)
     /// - [`get_plain_form`](Self::get_plain_form)
     fn get_form_controls(self, style: Self::Style, context: Self::Context) -> Form<Self> {
         let builder = FormBuilder::new(context);
@@ -213,12 +884,53 @@ pub trait FormToolData: Clone + 'static {
     ///
     /// However, the code to render the views are not configured out, it
     /// simply doesn't run, so the view needs to compile even on the server.
+    ///
+    /// This method never touches the DOM, so it is safe to call on the
+    /// server.
     fn get_validator(context: Self::Context) -> FormValidator<Self> {
         let builder = FormBuilder::new(context);
         let builder = Self::build_form(builder);
         builder.validator()
     }
 
+    /// Gets a [`FormValidator`] for this [`FormToolData`] once the context
+    /// has been produced.
+    ///
+    /// This is useful when the context needs to be loaded asynchronously,
+    /// like a list of valid values fetched from a database. The context
+    /// future is awaited, then the validator is built with
+    /// [`get_validator`](Self::get_validator)().
+    ///
+    /// Like [`get_validator`](Self::get_validator)(), this is safe to call
+    /// on the server.
+    fn get_validator_async(
+        context: impl Future<Output = Self::Context>,
+    ) -> impl Future<Output = FormValidator<Self>> {
+        async move { Self::get_validator(context.await) }
+    }
+
+    /// Gets a [`FormSimulator`] for this [`FormToolData`].
+    ///
+    /// Like [`get_validator`](Self::get_validator)(), this doesn't render
+    /// the view, so it can be used in unit tests without a browser.
+    fn get_simulator(context: Self::Context) -> FormSimulator<Self> {
+        let builder = FormBuilder::new(context);
+        let builder = Self::build_form(builder);
+        builder.simulator()
+    }
+
+    /// Gets the names of all the named controls in the form, in the order
+    /// they are added.
+    ///
+    /// With progressive enhancement, these names need to match the fields of
+    /// the server function's arguments. This allows checking that in a unit
+    /// test, without rendering the form.
+    fn field_names(context: Self::Context) -> Vec<String> {
+        let builder = FormBuilder::new(context);
+        let builder = Self::build_form(builder);
+        builder.field_names
+    }
+
     /// Validates this [`FormToolData`] struct.
     ///
     /// This is shorthand for creating a validator with
@@ -228,4 +940,152 @@ pub trait FormToolData: Clone + 'static {
         let validator = Self::get_validator(context);
         validator.validate(self)
     }
+
+    /// Validates this [`FormToolData`] struct once the context has been
+    /// produced.
+    ///
+    /// This is shorthand for creating a validator with
+    /// [`get_validator_async`](Self::get_validator_async)()
+    /// and then calling `validator.validate(&self)`.
+    fn validate_async(
+        &self,
+        context: impl Future<Output = Self::Context>,
+    ) -> impl Future<Output = Result<(), String>> {
+        async move {
+            let validator = Self::get_validator_async(context).await;
+            validator.validate(self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::GridFormStyle;
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct TestFormData {
+        name: String,
+        age: u32,
+        nickname: String,
+        inert: bool,
+    }
+
+    impl FormToolData for TestFormData {
+        type Style = GridFormStyle;
+        type Context = ();
+
+        fn build_form(fb: FormBuilder<Self>) -> FormBuilder<Self> {
+            fb.default_parse_error(|_| String::from("Please enter a valid value"))
+                .text_input(|c| {
+                    c.named("name")
+                        .parse_string()
+                        .validation_fn(|fd: &TestFormData| {
+                            if fd.name.is_empty() {
+                                Err(String::from("Name is required"))
+                            } else {
+                                Ok(())
+                            }
+                        })
+                        .getter(|fd| fd.name.clone())
+                        .setter(|fd, name| fd.name = name)
+                })
+                .stepper(|c| {
+                    c.named("age")
+                        .parse_primitive()
+                        .getter(|fd| fd.age)
+                        .setter(|fd, age| fd.age = age)
+                })
+                .text_input(|c| {
+                    c.named("nickname")
+                        .parse_string()
+                        .show_when(|fd, _| fd.with(|fd| fd.age >= 13))
+                        .getter(|fd| fd.nickname.clone())
+                        .setter(|fd, nickname| fd.nickname = nickname)
+                })
+                .validate_fields(|fd| {
+                    if fd.name == fd.nickname {
+                        Err(vec![(
+                            String::from("name"),
+                            String::from("Name must differ from the nickname"),
+                        )])
+                    } else {
+                        Ok(())
+                    }
+                })
+                .validation_gate(|fd| !fd.inert)
+        }
+    }
+
+    fn simulator() -> FormSimulator<TestFormData> {
+        TestFormData::get_simulator(())
+    }
+
+    #[test]
+    fn simulate_input_parses_and_validates() {
+        let (fd, state) =
+            simulator().simulate_input(TestFormData::default(), "name", String::from("Ann"));
+        assert_eq!(fd.name, "Ann");
+        assert_eq!(state, ValidationState::Passed);
+
+        let (fd, state) = simulator().simulate_input(fd, "name", String::new());
+        assert_eq!(fd.name, "");
+        assert_eq!(
+            state,
+            ValidationState::ValidationError(String::from("Name is required"))
+        );
+    }
+
+    #[test]
+    fn simulate_input_uses_default_parse_error() {
+        let fd = TestFormData {
+            age: 30,
+            ..Default::default()
+        };
+        let (fd, state) = simulator().simulate_input(fd, "age", String::from("abc"));
+        assert_eq!(fd.age, 30);
+        assert_eq!(
+            state,
+            ValidationState::ParseError(String::from("Please enter a valid value"))
+        );
+    }
+
+    #[test]
+    fn simulate_input_ignores_hidden_controls() {
+        let (fd, state) =
+            simulator().simulate_input(TestFormData::default(), "nickname", String::from("Al"));
+        assert_eq!(fd.nickname, "");
+        assert_eq!(state, ValidationState::Passed);
+
+        let fd = TestFormData {
+            age: 13,
+            ..Default::default()
+        };
+        let (fd, state) = simulator().simulate_input(fd, "nickname", String::from("Al"));
+        assert_eq!(fd.nickname, "Al");
+        assert_eq!(state, ValidationState::Passed);
+    }
+
+    #[test]
+    fn simulate_input_shows_field_validation_errors() {
+        let fd = TestFormData {
+            nickname: String::from("Al"),
+            ..Default::default()
+        };
+        let (_, state) = simulator().simulate_input(fd.clone(), "name", String::from("Al"));
+        assert_eq!(
+            state,
+            ValidationState::ValidationError(String::from("Name must differ from the nickname"))
+        );
+
+        let fd = TestFormData { inert: true, ..fd };
+        let (_, state) = simulator().simulate_input(fd, "name", String::from("Al"));
+        assert_eq!(state, ValidationState::Passed);
+    }
+
+    #[test]
+    #[should_panic(expected = "No control named \"missing\"")]
+    fn simulate_input_panics_on_unknown_control() {
+        simulator().simulate_input(TestFormData::default(), "missing", String::new());
+    }
 }
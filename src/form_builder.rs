@@ -1,19 +1,29 @@
 use crate::{
     controls::{
-        BuilderCxFn, BuilderFn, BuiltControlData, BuiltVanityControlData, ControlBuilder,
-        ControlData, ControlRenderData, FieldSetter, ParseFn, RenderFn, ValidationCb, ValidationFn,
-        ValidationState, VanityControlBuilder, VanityControlData,
+        BuilderCxFn, BuilderFn, BuiltControlData, BuiltVanityControlData, CheckFn, ConfirmAsyncFn,
+        ConfirmFn, ControlBuilder, ControlData, ControlRenderData, ControlState, ExcludeFn,
+        FieldChangeFn, FieldGetter, FieldSetter, FieldsValidationFn, ParseErrorFn, ParseFn,
+        RenderFn, RenderState, ShowWhenFn, SimulateFn, SimulateState, UnparseFn, ValidationCb,
+        ValidationFn, ValidationState, VanityControlBuilder, VanityControlData, WrapFn,
+    },
+    form::{
+        validity, ErrorDisplay, Form, FormMethod, FormMode, FormSimulator, FormToolData,
+        FormValidator, MultipartAction,
     },
-    form::{Form, FormToolData, FormValidator},
     styles::FormStyle,
 };
 use leptos::{
-    server_fn::{client::Client, codec::PostUrl, request::ClientReq, ServerFn},
+    server_fn::{
+        client::Client,
+        codec::{MultipartData, MultipartFormData, PostUrl},
+        request::ClientReq,
+        ServerFn,
+    },
     *,
 };
 use leptos_router::{ActionForm, Form};
 use serde::de::DeserializeOwned;
-use std::rc::Rc;
+use std::{collections::HashMap, future::Future, pin::Pin, rc::Rc};
 use web_sys::{FormData, SubmitEvent};
 
 /// A builder for laying out forms.
@@ -21,12 +31,49 @@ use web_sys::{FormData, SubmitEvent};
 /// This builder allows you to specify what components should make up the form.
 pub struct FormBuilder<FD: FormToolData> {
     pub(crate) cx: Rc<FD::Context>,
-    /// The list of [`ValidationFn`]s.
-    pub(crate) validations: Vec<Rc<dyn ValidationFn<FD>>>,
+    /// The list of validations, which return the name of each field that
+    /// failed along with its message.
+    pub(crate) validations: Vec<Rc<dyn FieldsValidationFn<FD>>>,
+    /// The function that decides if the validations should run at all.
+    pub(crate) validation_gate: Option<Rc<dyn CheckFn<FD>>>,
+    /// The named `show_when` conditions that controls can refer to.
+    pub(crate) conditions: HashMap<String, Rc<dyn ShowWhenFn<FD, FD::Context>>>,
     /// The list of functions that will render the form.
     pub(crate) render_fns: Vec<Box<dyn RenderFn<FD::Style, FD>>>,
     /// The list of styling attributes applied on the form level.
     pub(crate) styles: Vec<<FD::Style as FormStyle>::StylingAttributes>,
+    /// Whether to scroll to the first invalid control when submitting fails.
+    pub(crate) scroll_to_error: bool,
+    /// Whether to disable the browser's native validation of the form.
+    pub(crate) novalidate: bool,
+    /// Whether pressing Ctrl/Cmd+Enter in the form submits it.
+    pub(crate) submit_on_ctrl_enter: bool,
+    /// Whether to set the native html validation attributes on controls.
+    pub(crate) native_validation: bool,
+    /// The HTTP method of a plain form.
+    pub(crate) method: FormMethod,
+    /// The character encodings that a plain form accepts.
+    pub(crate) accept_charset: Option<String>,
+    /// The list of functions that reset excluded fields before submitting.
+    pub(crate) exclusions: Vec<Rc<dyn ExcludeFn<FD>>>,
+    /// The list of functions that simulate input to the named controls.
+    pub(crate) simulators: Vec<(String, Rc<dyn SimulateFn<FD>>)>,
+    /// The names of all the named controls, in order.
+    pub(crate) field_names: Vec<String>,
+    /// How the controls of the form are rendered.
+    pub(crate) mode: FormMode,
+    /// Where the error messages of the form are shown.
+    pub(crate) error_display: ErrorDisplay,
+    /// The function to call when a named field is changed by the user.
+    pub(crate) on_field_change: Option<Rc<dyn FieldChangeFn<FD>>>,
+    /// The function to wrap the view of every control with.
+    pub(crate) wrap_each: Option<Rc<dyn WrapFn>>,
+    /// The function to format parse errors that have no custom message.
+    pub(crate) parse_error_fn: Option<Rc<dyn ParseErrorFn>>,
+    /// The function to confirm submitting the form.
+    pub(crate) confirm_fn: Option<Rc<dyn ConfirmFn<FD>>>,
+    /// The async function to confirm submitting the form.
+    pub(crate) confirm_async_fn: Option<Rc<dyn ConfirmAsyncFn<FD>>>,
 }
 
 impl<FD: FormToolData> FormBuilder<FD> {
@@ -35,8 +82,26 @@ impl<FD: FormToolData> FormBuilder<FD> {
         FormBuilder {
             cx: Rc::new(cx),
             validations: Vec::new(),
+            validation_gate: None,
+            conditions: HashMap::new(),
             render_fns: Vec::new(),
             styles: Vec::new(),
+            scroll_to_error: false,
+            novalidate: false,
+            submit_on_ctrl_enter: false,
+            native_validation: false,
+            method: FormMethod::default(),
+            accept_charset: None,
+            exclusions: Vec::new(),
+            simulators: Vec::new(),
+            field_names: Vec::new(),
+            mode: FormMode::default(),
+            error_display: ErrorDisplay::default(),
+            on_field_change: None,
+            wrap_each: None,
+            parse_error_fn: None,
+            confirm_fn: None,
+            confirm_async_fn: None,
         }
     }
 
@@ -46,17 +111,354 @@ impl<FD: FormToolData> FormBuilder<FD> {
         FormBuilder {
             cx,
             validations: Vec::new(),
+            validation_gate: None,
+            conditions: HashMap::new(),
             render_fns: Vec::new(),
             styles: Vec::new(),
+            scroll_to_error: false,
+            novalidate: false,
+            submit_on_ctrl_enter: false,
+            native_validation: false,
+            method: FormMethod::default(),
+            accept_charset: None,
+            exclusions: Vec::new(),
+            simulators: Vec::new(),
+            field_names: Vec::new(),
+            mode: FormMode::default(),
+            error_display: ErrorDisplay::default(),
+            on_field_change: None,
+            wrap_each: None,
+            parse_error_fn: None,
+            confirm_fn: None,
+            confirm_async_fn: None,
         }
     }
 
+    /// Gets the form's context.
+    ///
+    /// This can be used to change the layout of the form based on the
+    /// context, without needing the `_cx` variant of every control.
+    pub fn context(&self) -> Rc<FD::Context> {
+        self.cx.clone()
+    }
+
+    /// Adds controls to the form for each item in `iter`.
+    ///
+    /// `builder` is called with the form builder and each item in turn,
+    /// and can add any number of controls. This is useful for forms
+    /// generated from data, like a settings page built from a list of
+    /// options.
+    pub fn for_each<I: IntoIterator>(
+        self,
+        iter: I,
+        builder: impl Fn(FormBuilder<FD>, I::Item) -> FormBuilder<FD>,
+    ) -> Self {
+        iter.into_iter().fold(self, builder)
+    }
+
     /// Adds a styling attribute to the entire form.
     pub fn style(mut self, style: <FD::Style as FormStyle>::StylingAttributes) -> Self {
         self.styles.push(style);
         self
     }
 
+    /// Scrolls the first invalid control into view when the form fails to
+    /// submit due to a parse or validation error.
+    ///
+    /// This is helpful on long forms, where the invalid control might not be
+    /// on screen when the user clicks submit.
+    pub fn scroll_to_error(mut self) -> Self {
+        self.scroll_to_error = true;
+        self
+    }
+
+    /// Disables the browser's native validation of the form.
+    ///
+    /// This sets the `novalidate` attribute on the form element, so only
+    /// this crate's validation runs, and native error popups for attributes
+    /// like `required` won't show alongside the form's own error messages.
+    ///
+    /// Without this, the browser's native validation runs first, and the
+    /// form's validation only runs once the native validation passes. This
+    /// has no effect on forms built with
+    /// [`get_form_controls`](crate::FormToolData::get_form_controls), as
+    /// they have no form element.
+    pub fn novalidate(mut self) -> Self {
+        self.novalidate = true;
+        self
+    }
+
+    /// Makes the validations only run when the `gate` function returns
+    /// true.
+    ///
+    /// When `gate` returns false, validating the form with the
+    /// [`FormValidator`] succeeds right away, without running any of the
+    /// other validations. Submitting the form in the browser also skips
+    /// the validation of its controls. This is useful for forms that are
+    /// conditionally inert, like when the feature they configure is
+    /// disabled, without needing to remove the validations. If this is
+    /// called more than once, all the gates must pass.
+    ///
+    /// This does not change the validation of the individual controls
+    /// while the user is filling out the form.
+    pub fn validation_gate(mut self, gate: impl Fn(&FD) -> bool + 'static) -> Self {
+        self.validation_gate = Some(match self.validation_gate.take() {
+            Some(prev) => Rc::new(move |fd: &FD| prev(fd) && gate(fd)),
+            None => Rc::new(gate),
+        });
+        self
+    }
+
+    /// Defines a named condition that controls can use to decide when to
+    /// render with `show_when_named`.
+    ///
+    /// This lets the same condition be shared by many controls without
+    /// repeating the closure. Conditions must be defined before the
+    /// controls that use them, and are inherited by groups. Defining a
+    /// condition with a name that is already used replaces it.
+    pub fn define_condition(
+        mut self,
+        name: impl ToString,
+        when: impl Fn(Signal<FD>, Rc<FD::Context>) -> bool + 'static,
+    ) -> Self {
+        self.conditions.insert(name.to_string(), Rc::new(when));
+        self
+    }
+
+    /// Sets native html validation attributes, like `required`, `min`, and
+    /// `maxlength`, on the controls that were validated with a
+    /// [`ValidationBuilder`](crate::ValidationBuilder).
+    ///
+    /// This lets the browser enforce the basic rules of the form when
+    /// javascript is not available, like before the page is hydrated.
+    /// Only the rules with an html equivalent are mirrored; see
+    /// [`NativeValidation`](crate::NativeValidation). The validation must be
+    /// set with the control builder's `validation` method, not
+    /// `validation_fn`, for the rules to be known. This has no effect if the
+    /// form also uses [`novalidate`](Self::novalidate).
+    ///
+    /// The browser's rules don't always match the validation exactly:
+    /// - `min` and `max` are not set on scaled or logarithmic controls, as
+    ///   the browser would check the shown value rather than the stored one.
+    /// - `minlength` and `maxlength` count UTF-16 code units, while the
+    ///   validation counts characters, so characters like emoji count as two
+    ///   for the browser.
+    /// - The browser can't show errors on hidden inputs. The grid style opens
+    ///   a collapsed accordion section when one of its inputs is invalid, but
+    ///   that needs javascript.
+    pub fn native_validation_fallback(mut self) -> Self {
+        self.native_validation = true;
+        self
+    }
+
+    /// Submits the form when Ctrl+Enter, or Cmd+Enter on macOS, is pressed
+    /// in any of the form's controls.
+    ///
+    /// This goes through the same validation as clicking the submit button.
+    /// It is useful for forms with text areas, where pressing Enter adds a
+    /// new line instead of submitting. This has no effect on forms built
+    /// with [`get_form_controls`](crate::FormToolData::get_form_controls),
+    /// as they have no form element.
+    pub fn submit_on_ctrl_enter(mut self) -> Self {
+        self.submit_on_ctrl_enter = true;
+        self
+    }
+
+    /// Sets the HTTP method that the form is submitted with.
+    ///
+    /// Defaults to [`FormMethod::Get`]. This only applies to forms built
+    /// with [`get_plain_form`](crate::FormToolData::get_plain_form), as the
+    /// other forms always send their data with a post request.
+    pub fn method(mut self, method: FormMethod) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Sets the `accept-charset` attribute of the form, which is the
+    /// character encodings that the server accepts, like "UTF-8".
+    ///
+    /// This only applies to forms built with
+    /// [`get_plain_form`](crate::FormToolData::get_plain_form).
+    pub fn accept_charset(mut self, accept_charset: impl ToString) -> Self {
+        self.accept_charset = Some(accept_charset.to_string());
+        self
+    }
+
+    /// Sets how the controls of the form are rendered.
+    ///
+    /// In [`FormMode::Display`], the interactive controls are rendered as
+    /// read only text, so the same form can be used for editing and viewing
+    /// the data. This applies to the whole form, including groups.
+    pub fn mode(mut self, mode: FormMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets where the error messages of the form are shown.
+    ///
+    /// With [`ErrorDisplay::Summary`], the controls don't show their own
+    /// error messages, and a summary of them is shown above the form once
+    /// submitting fails. This does not apply to forms built with
+    /// [`get_form_controls`](crate::FormToolData::get_form_controls), as
+    /// they are never submitted.
+    pub fn error_display(mut self, error_display: ErrorDisplay) -> Self {
+        self.error_display = error_display;
+        self
+    }
+
+    /// Sets a function to call whenever the user changes a named field.
+    ///
+    /// The function is given the name of the field, and the form data after
+    /// the change. Unlike watching the form data signal, this identifies
+    /// which field was changed, which can be useful for analytics.
+    pub fn on_field_change(mut self, on_field_change: impl Fn(&str, &FD) + 'static) -> Self {
+        self.on_field_change = Some(Rc::new(on_field_change));
+        self
+    }
+
+    /// Sets a function to wrap the rendered view of every control with.
+    ///
+    /// This is useful for decorating all the controls of the form the same
+    /// way, like putting each one in a card, without changing the
+    /// [`FormStyle`]. Hidden controls are not wrapped, so no empty wrappers
+    /// are left behind.
+    pub fn wrap_each(mut self, wrap_each: impl Fn(View) -> View + 'static) -> Self {
+        self.wrap_each = Some(Rc::new(wrap_each));
+        self
+    }
+
+    /// Sets a function to format the parse errors of all the controls in
+    /// the form.
+    ///
+    /// Parse functions like [`parse_string`](crate::controls::ControlBuilder::parse_string)()
+    /// use the error of the type's parse function as the message, like
+    /// "invalid digit found in string". This replaces those messages with
+    /// friendlier ones, like "Please enter a valid value". The function is
+    /// given the original message.
+    ///
+    /// Messages set on a control, like with
+    /// [`parse_string_msg`](crate::controls::ControlBuilder::parse_string_msg)(),
+    /// take priority.
+    pub fn default_parse_error(
+        mut self,
+        parse_error_fn: impl Fn(&str) -> String + 'static,
+    ) -> Self {
+        self.parse_error_fn = Some(Rc::new(parse_error_fn));
+        self
+    }
+
+    /// Sets a function to confirm submitting the form.
+    ///
+    /// The function runs once the form has passed validation, and submitting
+    /// is cancelled if it returns false. This is useful for destructive
+    /// actions, like deleting an account. For a simple confirmation dialog,
+    /// see [`confirm_submit_msg`](Self::confirm_submit_msg).
+    pub fn confirm_submit(mut self, confirm_fn: impl Fn(&FD) -> bool + 'static) -> Self {
+        self.confirm_fn = Some(Rc::new(confirm_fn));
+        self.confirm_async_fn = None;
+        self
+    }
+
+    /// Asks the user to confirm submitting the form with the browser's
+    /// confirmation dialog, showing the given message.
+    ///
+    /// See [`confirm_submit`](Self::confirm_submit).
+    pub fn confirm_submit_msg(self, msg: impl ToString) -> Self {
+        let msg = msg.to_string();
+        self.confirm_submit(move |_| window().confirm_with_message(&msg).unwrap_or(false))
+    }
+
+    /// Sets an async function to confirm submitting the form.
+    ///
+    /// This is like [`confirm_submit`](Self::confirm_submit), but the future
+    /// is awaited before submitting, which allows using a custom modal.
+    /// Since the form can't wait to be submitted, this only applies to forms
+    /// built with [`get_form`](crate::FormToolData::get_form) or
+    /// [`get_form_async`](crate::FormToolData::get_form_async), where the
+    /// server function is called directly.
+    pub fn confirm_submit_async<Fut: Future<Output = bool> + 'static>(
+        mut self,
+        confirm_fn: impl Fn(&FD) -> Fut + 'static,
+    ) -> Self {
+        self.confirm_async_fn = Some(Rc::new(move |fd: &FD| {
+            Box::pin(confirm_fn(fd)) as Pin<Box<dyn Future<Output = bool>>>
+        }));
+        self.confirm_fn = None;
+        self
+    }
+
+    /// Adds a validation that can fail on several fields at once.
+    ///
+    /// This is for rules that span multiple fields, like percentages that
+    /// must add up to 100%. On failure, the function returns the name of
+    /// each field that should show an error, along with the message to show
+    /// on it. The errors are shown when the form is submitted, and are
+    /// updated as the form data changes after that.
+    ///
+    /// With the [`FormValidator`], the messages are joined into one error,
+    /// or keyed by their fields with
+    /// [`validate_to_map`](FormValidator::validate_to_map).
+    pub fn validate_fields(
+        mut self,
+        validation_fn: impl Fn(&FD) -> Result<(), Vec<(String, String)>> + 'static,
+    ) -> Self {
+        let validation_fn = Rc::new(validation_fn);
+
+        self.validations.push(validation_fn.clone());
+
+        let render_fn = move |_, fd: RwSignal<FD>, render_state: RenderState<FD>| {
+            let cb = Self::route_field_errors(validation_fn, fd, render_state.control_states);
+            (View::default(), Some(Box::new(cb) as Box<dyn ValidationCb>))
+        };
+        self.render_fns.push(Box::new(render_fn));
+        self
+    }
+
+    /// Creates the validation callback for
+    /// [`validate_fields`](Self::validate_fields), which sets the errors on
+    /// the named controls.
+    fn route_field_errors(
+        validation_fn: Rc<dyn FieldsValidationFn<FD>>,
+        fd: RwSignal<FD>,
+        control_states: StoredValue<Vec<ControlState>>,
+    ) -> impl ValidationCb {
+        // the fields that currently have an error from this validation
+        let routed = store_value(Vec::<String>::new());
+        let route = move |errors: Vec<(String, String)>| {
+            control_states.with_value(|states| {
+                for state in states.iter() {
+                    let msg = errors
+                        .iter()
+                        .find(|(name, _)| *name == state.name)
+                        .map(|(_, msg)| msg.clone());
+                    let was_routed = routed.with_value(|routed| routed.contains(&state.name));
+                    if msg.is_some() || was_routed {
+                        state.external_error.set(msg);
+                    }
+                }
+            });
+            routed.set_value(errors.into_iter().map(|(name, _)| name).collect());
+        };
+
+        // only start showing the errors once the form has been validated
+        let active = store_value(false);
+        let effect_validation_fn = validation_fn.clone();
+        create_effect(move |_| {
+            let result = fd.with(|fd| effect_validation_fn(fd));
+            if active.get_value() {
+                route(result.err().unwrap_or_default());
+            }
+        });
+
+        move || {
+            active.set_value(true);
+            let result = fd.with_untracked(|fd| validation_fn(fd));
+            let succeeded = result.is_ok();
+            route(result.err().unwrap_or_default());
+            succeeded
+        }
+    }
+
     /// Adds a new vanity control to the form.
     pub(crate) fn new_vanity<C: VanityControlData<FD> + Default>(
         mut self,
@@ -101,34 +503,117 @@ impl<FD: FormToolData> FormBuilder<FD> {
         self
     }
 
+    /// Gets the condition with the given name.
+    ///
+    /// # Panics
+    /// Panics if no condition with that name has been defined.
+    pub(crate) fn condition(&self, name: &str) -> Rc<dyn ShowWhenFn<FD, FD::Context>> {
+        match self.conditions.get(name) {
+            Some(condition) => condition.clone(),
+            None => panic!(
+                "Unknown condition ({}): it must be defined before it is used",
+                name
+            ),
+        }
+    }
+
+    /// Checks the `show_when` condition against a copy of the form data,
+    /// outside of the rendered form.
+    pub(crate) fn is_shown(
+        show_when: &dyn ShowWhenFn<FD, FD::Context>,
+        fd: &FD,
+        cx: &Rc<FD::Context>,
+    ) -> bool {
+        let fd_signal = create_rw_signal(fd.clone());
+        let shown = show_when(fd_signal.into(), cx.clone());
+        fd_signal.dispose();
+        shown
+    }
+
     /// Adds a vanity control to the form.
     pub(crate) fn add_vanity<C: VanityControlData<FD>>(
         &mut self,
         vanity_control: VanityControlBuilder<FD, C>,
     ) {
+        self.add_vanity_with_state(vanity_control, |_, _| {});
+    }
+
+    /// Adds a vanity control to the form, letting `prepare` fill in the
+    /// control's data from the form's render state before it is rendered.
+    pub(crate) fn add_vanity_with_state<C: VanityControlData<FD>>(
+        &mut self,
+        vanity_control: VanityControlBuilder<FD, C>,
+        prepare: impl FnOnce(&mut C, &RenderState<FD>) + 'static,
+    ) {
+        self.add_vanity_with_render(vanity_control, move |data, render_state| {
+            prepare(data, render_state);
+            C::render_control::<FD::Style>
+        });
+    }
+
+    /// Adds a vanity control to the form, letting `prepare` fill in the
+    /// control's data from the form's render state, and return the function
+    /// that renders the control.
+    pub(crate) fn add_vanity_with_render<C, R>(
+        &mut self,
+        mut vanity_control: VanityControlBuilder<FD, C>,
+        prepare: impl FnOnce(&mut C, &RenderState<FD>) -> R + 'static,
+    ) where
+        C: VanityControlData<FD>,
+        R: Fn(
+                &FD::Style,
+                RwSignal<FD>,
+                Rc<ControlRenderData<FD::Style, C>>,
+                Option<Signal<String>>,
+            ) -> View
+            + 'static,
+    {
+        if let Some(name) = vanity_control.show_when_named.take() {
+            let condition = self.condition(&name);
+            vanity_control.show_when = Some(Box::new(move |fd, cx| condition(fd, cx)));
+        }
         let BuiltVanityControlData {
-            render_data,
+            mut render_data,
             getter,
+            getter_deps,
             show_when,
         } = vanity_control.build();
 
+        if let Some(name) = render_data.data.name() {
+            self.field_names.push(name.to_string());
+        }
+
         let cx = self.cx.clone();
-        let render_fn = move |fs: Rc<FD::Style>, fd: RwSignal<FD>| {
-            let render_data = Rc::new(render_data);
-            let value_getter =
-                getter.map(|getter| (move || fd.with(|fd| getter(fd))).into_signal());
-            let view = move || {
-                VanityControlData::render_control(&*fs, fd, render_data.clone(), value_getter)
-            };
-            let view = match show_when {
-                Some(when) => {
-                    let when = move || when(fd.into(), cx.clone());
-                    view! { <Show when=when>{view.clone()}</Show> }
-                }
-                None => view(),
+        let render_fn =
+            move |fs: Rc<FD::Style>, fd: RwSignal<FD>, render_state: RenderState<FD>| {
+                let render = Rc::new(prepare(&mut render_data.data, &render_state));
+                let wrap_each = render_state.wrap_each;
+                let render_data = Rc::new(render_data);
+                let value_getter = getter.map(|getter| match getter_deps {
+                    Some(getter_deps) => {
+                        // only run the getter when its dependencies change
+                        let deps = getter_deps(fd);
+                        create_memo(move |_| {
+                            deps.track();
+                            fd.with_untracked(|fd| getter(fd))
+                        })
+                        .into()
+                    }
+                    None => (move || fd.with(|fd| getter(fd))).into_signal(),
+                });
+                let view = move || {
+                    let view = render(&*fs, fd, render_data.clone(), value_getter);
+                    RenderState::<FD>::wrap(&wrap_each, view)
+                };
+                let view = match show_when {
+                    Some(when) => {
+                        let when = move || when(fd.into(), cx.clone());
+                        view! { <Show when=when>{view.clone()}</Show> }
+                    }
+                    None => view(),
+                };
+                (view, None)
             };
-            (view, None)
-        };
 
         self.render_fns.push(Box::new(render_fn));
     }
@@ -136,8 +621,11 @@ impl<FD: FormToolData> FormBuilder<FD> {
     /// Adds a control to the form.
     pub(crate) fn add_control<C: ControlData<FD>, FDT: Clone + PartialEq + 'static>(
         &mut self,
-        control: ControlBuilder<FD, C, FDT>,
+        mut control: ControlBuilder<FD, C, FDT>,
     ) {
+        if let Some(name) = control.show_when_named.take() {
+            control.show_when = Some(self.condition(&name));
+        }
         let built_control_data = match control.build() {
             Ok(c) => c,
             Err(e) => {
@@ -155,8 +643,7 @@ impl<FD: FormToolData> FormBuilder<FD> {
                 // thus, we need to modify the validation function
                 let cx = self.cx.clone();
                 let new_validation_fn = move |fd: &FD| {
-                    let (fd_signal, _) = create_signal(fd.clone());
-                    if !show_when(fd_signal.into(), cx.clone()) {
+                    if !Self::is_shown(&*show_when, fd, &cx) {
                         return Ok(());
                     }
                     validation_fn(fd)
@@ -166,12 +653,83 @@ impl<FD: FormToolData> FormBuilder<FD> {
                 validation_fn
             };
 
-            self.validations.push(validation_fn);
+            let name = built_control_data
+                .render_data
+                .data
+                .name()
+                .unwrap_or_default()
+                .to_string();
+            self.validations.push(Rc::new(move |fd: &FD| {
+                validation_fn(fd).map_err(|msg| vec![(name.clone(), msg)])
+            }));
+        }
+
+        if let Some(exclude_when) = built_control_data.exclude_when.clone() {
+            let setter = built_control_data.setter.clone();
+            let exclude_fn = move |fd: &mut FD| {
+                if let Some(value) = exclude_when(fd) {
+                    setter(fd, value);
+                }
+            };
+            self.exclusions.push(Rc::new(exclude_fn));
+        }
+
+        if let Some(name) = built_control_data.render_data.data.name() {
+            self.field_names.push(name.to_string());
+            let parse_fn = built_control_data.parse_fn.clone();
+            let custom_parse_msg = built_control_data.custom_parse_msg;
+            let setter = built_control_data.setter.clone();
+            let validation_fn = built_control_data.validation_fn.clone();
+            let show_when = built_control_data.show_when.clone();
+            let cx = self.cx.clone();
+            let control_name = name.to_string();
+            // this follows what the rendered control does in `build_control_view`
+            let simulate_fn = move |fd: &mut FD,
+                                    value: Box<dyn std::any::Any>,
+                                    state: &SimulateState<FD>| {
+                // hidden controls can't be changed, and always pass validation
+                if let Some(ref show_when) = show_when {
+                    if !Self::is_shown(&**show_when, fd, &cx) {
+                        return ValidationState::Passed;
+                    }
+                }
+
+                let value = match value.downcast::<C::ReturnType>() {
+                    Ok(value) => *value,
+                    Err(_) => panic!(
+                        "Invalid input for control \"{}\": expected a value of type {}",
+                        control_name,
+                        std::any::type_name::<C::ReturnType>()
+                    ),
+                };
+                let parsed = match parse_fn(value) {
+                    Ok(parsed) => parsed,
+                    Err(e) => {
+                        let e = match state.parse_error_fn.as_ref().filter(|_| !custom_parse_msg) {
+                            Some(parse_error_fn) => parse_error_fn(&e),
+                            None => e,
+                        };
+                        return ValidationState::ParseError(e);
+                    }
+                };
+                setter(fd, parsed);
+
+                if let Some(Err(e)) = validation_fn.as_ref().map(|v| v(fd)) {
+                    return ValidationState::ValidationError(e);
+                }
+                // errors from validations of the whole form show when the control has none
+                match state.field_error(fd, &control_name) {
+                    Some(e) => ValidationState::ValidationError(e),
+                    None => ValidationState::Passed,
+                }
+            };
+            self.simulators
+                .push((name.to_string(), Rc::new(simulate_fn)));
         }
 
         let cx = self.cx.clone();
-        let render_fn = move |fs: Rc<FD::Style>, fd: RwSignal<FD>| {
-            let (view, cb) = Self::build_control_view(fd, fs, built_control_data, cx);
+        let render_fn = move |fs: Rc<FD::Style>, fd: RwSignal<FD>, render_state| {
+            let (view, cb) = Self::build_control_view(fd, fs, built_control_data, cx, render_state);
             (view, Some(cb))
         };
 
@@ -180,27 +738,121 @@ impl<FD: FormToolData> FormBuilder<FD> {
 
     /// Helper for building all the functions and everything needed to render
     /// the view.
-    fn build_control_view<C: ControlData<FD>, FDT: 'static>(
+    ///
+    /// This only runs once the form is rendered, never when just building a
+    /// validator. Nothing here should access the DOM directly, as this also
+    /// runs on the server with SSR.
+    fn build_control_view<C: ControlData<FD>, FDT: PartialEq + 'static>(
         fd: RwSignal<FD>,
         fs: Rc<FD::Style>,
         control_data: BuiltControlData<FD, C, FDT>,
         cx: Rc<FD::Context>,
+        render_state: RenderState<FD>,
     ) -> (View, Box<dyn ValidationCb>) {
+        let RenderState {
+            control_states,
+            mode,
+            error_display,
+            on_field_change,
+            wrap_each,
+            parse_error_fn,
+            native_validation,
+            ..
+        } = render_state;
         let BuiltControlData {
-            render_data,
+            mut render_data,
             getter,
             setter,
             parse_fn,
             unparse_fn,
             validation_fn,
+            checklist,
             show_when,
+            defer_unparse,
+            keep_invalid_input,
+            custom_parse_msg,
+            revalidate_on,
+            details_help,
+            native_validation: control_native_validation,
+            ..
         } = control_data;
 
+        let parse_fn = match parse_error_fn.filter(|_| !custom_parse_msg) {
+            Some(parse_error_fn) => {
+                let parse_fn: Rc<dyn ParseFn<C::ReturnType, FDT>> =
+                    Rc::new(move |value| parse_fn(value).map_err(|e| parse_error_fn(&e)));
+                parse_fn
+            }
+            None => parse_fn,
+        };
+
+        render_data.inline_errors = error_display.inline();
+        render_data.native_validation = control_native_validation.filter(|_| native_validation);
+        let input_ref = *render_data.node_ref.get_or_insert_with(create_node_ref);
         let render_data = Rc::new(render_data);
         let (validation_signal, validation_signal_set) = create_signal(ValidationState::Passed);
-        let validation_fn_clone = validation_fn.clone();
+        // errors from validations of the whole form show when the control has none
+        let external_error = create_rw_signal(None::<String>);
+        let validation_state = Signal::derive(move || {
+            let validation_state = validation_signal.get();
+            match external_error.get() {
+                Some(e) if validation_state.is_passed() => ValidationState::ValidationError(e),
+                _ => validation_state,
+            }
+        });
+        let (touched, touched_set) = create_signal(false);
+        let node_ref = create_node_ref::<html::AnyElement>();
+        let unparse_fn: Rc<dyn UnparseFn<C::ReturnType, FDT>> = Rc::from(unparse_fn);
         let initial_value = unparse_fn(fd.with_untracked(|fd| getter(fd)));
+        // what is currently in the control, even if it failed to parse
+        let raw_value = create_rw_signal(initial_value.clone());
+        if let Some(name) = render_data.data.name() {
+            let complete_validation_fn = validation_fn.clone();
+            let complete_show_when = show_when.clone();
+            let complete_cx = cx.clone();
+            let complete = Signal::derive(move || {
+                let validation_fn = complete_validation_fn.as_ref()?;
+                if let Some(ref show_when) = complete_show_when {
+                    if !show_when(fd.into(), complete_cx.clone()) {
+                        return None;
+                    }
+                }
+                Some(fd.with(|fd| validation_fn(fd).is_ok()))
+            });
+            let control_state = ControlState {
+                name: name.to_string(),
+                validation_state,
+                external_error,
+                touched: touched.into(),
+                node_ref,
+                input_ref,
+                checklist: Signal::derive(move || {
+                    fd.with(|fd| {
+                        checklist
+                            .iter()
+                            .map(|(name, check)| (name.clone(), check(fd)))
+                            .collect()
+                    })
+                }),
+                complete,
+                change: Self::change_signal(
+                    fd,
+                    render_data.clone(),
+                    getter.clone(),
+                    unparse_fn.clone(),
+                ),
+                raw_value: Rc::new(Signal::from(raw_value)),
+            };
+            control_states.update_value(|states| states.push(control_state));
+        }
+        let validation_fn_clone = validation_fn.clone();
         let (value_getter, value_setter) = create_signal(initial_value);
+        create_effect(move |_| raw_value.set(value_getter.get()));
+        // what the user last entered, if showing the unparsed value is deferred
+        let last_input = store_value(None::<C::ReturnType>);
+        let parse_fn_clone = parse_fn.clone();
+        let getter_clone = getter.clone();
+        let unparse_fn_clone = unparse_fn.clone();
         create_effect(move |_| {
             fd.track();
             if validation_signal.get().is_parse_err() {
@@ -220,10 +872,46 @@ impl<FD: FormToolData> FormBuilder<FD> {
                 }
             }
 
-            let value = unparse_fn(getter(&fd));
+            // keep showing what the user typed if it parses to the same value
+            let field = getter(&fd);
+            let typed_same = last_input.with_value(|last_input| {
+                last_input.as_ref().is_some_and(|input| {
+                    parse_fn_clone(input.clone()).is_ok_and(|parsed| parsed == field)
+                })
+            });
+            if typed_same {
+                return;
+            }
+
+            last_input.set_value(None);
+            let value = unparse_fn(field);
             value_setter.set(value);
         });
+        if let Some((trigger, validation_fn)) = revalidate_on.zip(validation_fn.clone()) {
+            create_effect(move |prev: Option<()>| {
+                trigger.track();
+                // the value hasn't changed on the first run
+                if prev.is_none() {
+                    return;
+                }
+                if !touched.get_untracked() || validation_signal.get_untracked().is_parse_err() {
+                    return;
+                }
+                let new_state = match fd.with_untracked(|fd| validation_fn(fd)) {
+                    Ok(()) => ValidationState::Passed,
+                    Err(e) => ValidationState::ValidationError(e),
+                };
+                validation_signal_set.set(new_state);
+            });
+        }
         let value_getter = value_getter.into();
+        let show_unparsed = move || {
+            if last_input.with_value(|l| l.is_none()) {
+                return;
+            }
+            last_input.set_value(None);
+            fd.with_untracked(|fd| value_setter.set(unparse_fn_clone(getter_clone(fd))));
+        };
 
         let validation_fn_clone = validation_fn.clone();
         let cloned_show_when = show_when.clone();
@@ -262,23 +950,69 @@ impl<FD: FormToolData> FormBuilder<FD> {
         };
         let validation_cb = Box::new(validation_cb);
 
+        let display_setter = value_setter;
+        let on_field_change = on_field_change
+            .zip(render_data.data.name())
+            .map(|(on_field_change, name)| (name.to_string(), on_field_change));
         let value_setter = Self::create_value_setter(
             validation_fn.clone(),
             validation_signal_set,
             parse_fn,
             setter,
             fd,
+            on_field_change,
         );
+        let value_setter = SignalSetter::map(move |value: C::ReturnType| {
+            touched_set.set(true);
+            raw_value.set(value.clone());
+            if defer_unparse {
+                last_input.set_value(Some(value.clone()));
+            }
+            if !keep_invalid_input {
+                value_setter.set(value);
+                return;
+            }
+            value_setter.set(value.clone());
+            // the form data was not updated, so show the invalid value directly
+            if validation_signal.get_untracked().is_parse_err() {
+                display_setter.set(value);
+            }
+        });
 
         let view = move || {
-            C::render_control(
-                &*fs,
-                fd,
-                render_data.clone(),
-                value_getter,
-                value_setter,
-                validation_signal.into(),
-            )
+            let view = match mode {
+                FormMode::Editable => C::render_control(
+                    &*fs,
+                    fd,
+                    render_data.clone(),
+                    value_getter,
+                    value_setter,
+                    validation_state,
+                    touched.into(),
+                ),
+                FormMode::Display => C::render_display(&*fs, fd, render_data.clone(), value_getter),
+            };
+            // keep a reference to the control's element, if it is one, and
+            // mark the control as touched when it loses focus
+            let show_unparsed = show_unparsed.clone();
+            let view = match view {
+                View::Element(element) => element
+                    .into_html_element()
+                    .on(ev::focusout, move |_| {
+                        touched_set.set(true);
+                        show_unparsed();
+                    })
+                    .node_ref(node_ref)
+                    .into_view(),
+                view => view,
+            };
+            let view = match (mode, details_help.clone()) {
+                (FormMode::Editable, Some((summary, content))) => {
+                    fs.details_help(view, summary, content)
+                }
+                _ => view,
+            };
+            RenderState::<FD>::wrap(&wrap_each, view)
         };
         let view = match show_when {
             Some(when) => {
@@ -290,13 +1024,31 @@ impl<FD: FormToolData> FormBuilder<FD> {
         (view, validation_cb)
     }
 
+    /// Creates the signal of the old and new text of a control's value,
+    /// which is `Some` once the value has changed from its initial value.
+    fn change_signal<C: ControlData<FD>, FDT: 'static>(
+        fd: RwSignal<FD>,
+        render_data: Rc<ControlRenderData<FD::Style, C>>,
+        getter: Rc<dyn FieldGetter<FD, FDT>>,
+        unparse_fn: Rc<dyn UnparseFn<C::ReturnType, FDT>>,
+    ) -> Signal<Option<(String, String)>> {
+        let value_text = move |fd: &FD| render_data.data.value_text(&unparse_fn(getter(fd)));
+        let initial_text = fd.with_untracked(&value_text);
+        Signal::derive(move || {
+            let initial_text = initial_text.clone()?;
+            let text = fd.with(&value_text)?;
+            (text != initial_text).then_some((initial_text, text))
+        })
+    }
+
     /// Helper for creating a setter function.
     fn create_value_setter<CRT: 'static, FDT: 'static>(
         validation_fn: Option<Rc<dyn ValidationFn<FD>>>,
         validation_signal_set: WriteSignal<ValidationState>,
-        parse_fn: Box<dyn ParseFn<CRT, FDT>>,
+        parse_fn: Rc<dyn ParseFn<CRT, FDT>>,
         setter: Rc<dyn FieldSetter<FD, FDT>>,
         fd: RwSignal<FD>,
+        on_field_change: Option<(String, Rc<dyn FieldChangeFn<FD>>)>,
     ) -> SignalSetter<CRT> {
         let value_setter = move |value| {
             let parsed = match parse_fn(value) {
@@ -311,6 +1063,9 @@ impl<FD: FormToolData> FormBuilder<FD> {
             fd.update(|data| {
                 setter(data, parsed);
             });
+            if let Some((ref name, ref on_field_change)) = on_field_change {
+                fd.with_untracked(|data| on_field_change(name, data));
+            }
 
             // run validation
             let validation_fn = match validation_fn {
@@ -341,6 +1096,57 @@ impl<FD: FormToolData> FormBuilder<FD> {
         fd: FD,
         fs: FD::Style,
     ) -> Form<FD>
+    where
+        ServFn: DeserializeOwned + ServerFn<InputEncoding = PostUrl> + 'static,
+        <<ServFn::Client as Client<ServFn::Error>>::Request as ClientReq<ServFn::Error>>::FormData:
+            From<FormData>,
+        ServFn: From<FD>,
+    {
+        let on_submit = move |ev, fd, dispatch: Box<dyn FnOnce()>| {
+            on_submit(ev, fd);
+            dispatch();
+        };
+        self.build_form_with(action, on_submit, fd, fs)
+    }
+
+    /// Builds the direct send version of the form, with an async submit
+    /// handler that is awaited before the action is dispatched.
+    pub(crate) fn build_form_async<ServFn, F, Fut>(
+        self,
+        action: Action<ServFn, Result<ServFn::Output, ServerFnError<ServFn::Error>>>,
+        on_submit: F,
+        fd: FD,
+        fs: FD::Style,
+    ) -> Form<FD>
+    where
+        F: Fn(SubmitEvent, RwSignal<FD>) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+        ServFn: DeserializeOwned + ServerFn<InputEncoding = PostUrl> + 'static,
+        <<ServFn::Client as Client<ServFn::Error>>::Request as ClientReq<ServFn::Error>>::FormData:
+            From<FormData>,
+        ServFn: From<FD>,
+    {
+        let on_submit = move |ev, fd, dispatch: Box<dyn FnOnce()>| {
+            let submit_future = on_submit(ev, fd);
+            spawn_local(async move {
+                submit_future.await;
+                dispatch();
+            });
+        };
+        self.build_form_with(action, on_submit, fd, fs)
+    }
+
+    /// Builds the direct send version of the form.
+    ///
+    /// The `on_submit` function is given a function to dispatch the action,
+    /// which it must call once it is done.
+    fn build_form_with<ServFn, F: Fn(SubmitEvent, RwSignal<FD>, Box<dyn FnOnce()>) + 'static>(
+        self,
+        action: Action<ServFn, Result<ServFn::Output, ServerFnError<ServFn::Error>>>,
+        on_submit: F,
+        fd: FD,
+        fs: FD::Style,
+    ) -> Form<FD>
     where
         ServFn: DeserializeOwned + ServerFn<InputEncoding = PostUrl> + 'static,
         <<ServFn::Client as Client<ServFn::Error>>::Request as ClientReq<ServFn::Error>>::FormData:
@@ -349,44 +1155,107 @@ impl<FD: FormToolData> FormBuilder<FD> {
     {
         let fd = create_rw_signal(fd);
         let fs = Rc::new(fs);
+        let (submitting, set_submitting) = create_signal(false);
+        let pending = action.pending();
+        let render_state =
+            self.render_state(Signal::derive(move || submitting.get() || pending.get()));
+        let control_states = render_state.control_states;
 
         let (views, validation_cbs): (Vec<_>, Vec<_>) = self
             .render_fns
             .into_iter()
-            .map(|r_fn| r_fn(fs.clone(), fd))
+            .map(|r_fn| r_fn(fs.clone(), fd, render_state.clone()))
             .unzip();
 
-        let elements = fs.form_frame(ControlRenderData {
-            data: views.into_view(),
-            styles: self.styles,
-        });
+        let (submit_failed, set_submit_failed) = create_signal(false);
+        let error_summary =
+            Self::error_summary(&fs, self.error_display, control_states, submit_failed);
+        let elements = fs.form_frame(
+            ControlRenderData {
+                data: views.into_view(),
+                styles: self.styles,
+                conditional_styles: Vec::new(),
+                node_ref: None,
+                tabindex: None,
+                inline_errors: true,
+                native_validation: None,
+            },
+            validity(control_states),
+        );
 
+        let scroll_to_error = self.scroll_to_error;
+        let validation_gate = self.validation_gate.clone();
+        let exclusions = Rc::new(self.exclusions);
+        let confirm_fn = self.confirm_fn;
+        let confirm_async_fn = self.confirm_async_fn;
+        let on_submit = Rc::new(on_submit);
         let on_submit = move |ev: SubmitEvent| {
             if ev.default_prevented() {
                 return;
             }
             ev.prevent_default();
-            for validation in validation_cbs.iter().flatten() {
-                if !validation() {
-                    return;
+            if !Self::controls_valid(&validation_cbs, &validation_gate, fd) {
+                set_submit_failed.set(true);
+                if scroll_to_error {
+                    Self::scroll_to_first_error(control_states);
                 }
+                return;
+            }
+            set_submit_failed.set(false);
+            if !Self::confirmed(&confirm_fn, fd) {
+                return;
             }
-            on_submit(ev, fd);
 
-            let server_fn = ServFn::from(fd.get_untracked());
-            action.dispatch(server_fn);
+            let exclusions = exclusions.clone();
+            let dispatch = move || {
+                set_submitting.set(false);
+                let mut data = fd.get_untracked();
+                for exclude_fn in exclusions.iter() {
+                    exclude_fn(&mut data);
+                }
+                let server_fn = ServFn::from(data);
+                action.dispatch(server_fn);
+            };
+            match confirm_async_fn {
+                Some(ref confirm_async_fn) => {
+                    let confirmed = fd.with_untracked(|fd| confirm_async_fn(fd));
+                    let on_submit = on_submit.clone();
+                    spawn_local(async move {
+                        if confirmed.await {
+                            set_submitting.set(true);
+                            on_submit(ev, fd, Box::new(dispatch));
+                        }
+                    });
+                }
+                None => {
+                    set_submitting.set(true);
+                    on_submit(ev, fd, Box::new(dispatch));
+                }
+            }
         };
 
+        let form_ref = create_node_ref::<html::Form>();
+        let novalidate = self.novalidate;
+        let on_keydown = Self::submit_shortcut(self.submit_on_ctrl_enter, form_ref);
         let view = view! {
-            <ActionForm action=action on:submit=on_submit>
+            <ActionForm
+                action=action
+                on:submit=on_submit
+                on:keydown=on_keydown
+                node_ref=form_ref
+                attr:novalidate=novalidate
+            >
+                {error_summary}
                 {elements}
             </ActionForm>
         };
 
         Form {
             fd,
-            validations: self.validations,
+            validations: Self::gate_validations(self.validations, self.validation_gate),
             view,
+            control_states,
+            node_ref: form_ref,
         }
     }
 
@@ -405,41 +1274,172 @@ impl<FD: FormToolData> FormBuilder<FD> {
     {
         let fd = create_rw_signal(fd);
         let fs = Rc::new(fs);
+        let render_state = self.render_state(action.pending().into());
+        let control_states = render_state.control_states;
 
         let (views, validation_cbs): (Vec<_>, Vec<_>) = self
             .render_fns
             .into_iter()
-            .map(|r_fn| r_fn(fs.clone(), fd))
+            .map(|r_fn| r_fn(fs.clone(), fd, render_state.clone()))
             .unzip();
 
-        let elements = fs.form_frame(ControlRenderData {
-            data: views.into_view(),
-            styles: self.styles,
-        });
+        let (submit_failed, set_submit_failed) = create_signal(false);
+        let error_summary =
+            Self::error_summary(&fs, self.error_display, control_states, submit_failed);
+        let elements = fs.form_frame(
+            ControlRenderData {
+                data: views.into_view(),
+                styles: self.styles,
+                conditional_styles: Vec::new(),
+                node_ref: None,
+                tabindex: None,
+                inline_errors: true,
+                native_validation: None,
+            },
+            validity(control_states),
+        );
 
+        let scroll_to_error = self.scroll_to_error;
+        let validation_gate = self.validation_gate.clone();
+        let confirm_fn = self.confirm_fn;
         let on_submit = move |ev: SubmitEvent| {
             if ev.default_prevented() {
                 return;
             }
-            for validation in validation_cbs.iter().flatten() {
-                if !validation() {
-                    ev.prevent_default();
-                    return;
+            if !Self::controls_valid(&validation_cbs, &validation_gate, fd) {
+                set_submit_failed.set(true);
+                ev.prevent_default();
+                if scroll_to_error {
+                    Self::scroll_to_first_error(control_states);
                 }
+                return;
+            }
+            set_submit_failed.set(false);
+            if !Self::confirmed(&confirm_fn, fd) {
+                ev.prevent_default();
+                return;
             }
             on_submit(ev, fd);
         };
 
+        let form_ref = create_node_ref::<html::Form>();
+        let novalidate = self.novalidate;
+        let on_keydown = Self::submit_shortcut(self.submit_on_ctrl_enter, form_ref);
         let view = view! {
-            <ActionForm action=action on:submit=on_submit>
+            <ActionForm
+                action=action
+                on:submit=on_submit
+                on:keydown=on_keydown
+                node_ref=form_ref
+                attr:novalidate=novalidate
+            >
+                {error_summary}
                 {elements}
             </ActionForm>
         };
 
         Form {
             fd,
-            validations: self.validations,
+            validations: Self::gate_validations(self.validations, self.validation_gate),
+            view,
+            control_states,
+            node_ref: form_ref,
+        }
+    }
+
+    /// Builds the multipart version of the form.
+    pub(crate) fn build_multipart_form<ServFn, F: Fn(SubmitEvent, RwSignal<FD>) + 'static>(
+        self,
+        action: MultipartAction<ServFn>,
+        on_submit: F,
+        fd: FD,
+        fs: FD::Style,
+    ) -> Form<FD>
+    where
+        ServFn: ServerFn<InputEncoding = MultipartFormData> + From<MultipartData> + 'static,
+    {
+        let action = action.action();
+        let fd = create_rw_signal(fd);
+        let fs = Rc::new(fs);
+        let render_state = self.render_state(action.pending().into());
+        let control_states = render_state.control_states;
+
+        let (views, validation_cbs): (Vec<_>, Vec<_>) = self
+            .render_fns
+            .into_iter()
+            .map(|r_fn| r_fn(fs.clone(), fd, render_state.clone()))
+            .unzip();
+
+        let (submit_failed, set_submit_failed) = create_signal(false);
+        let error_summary =
+            Self::error_summary(&fs, self.error_display, control_states, submit_failed);
+        let elements = fs.form_frame(
+            ControlRenderData {
+                data: views.into_view(),
+                styles: self.styles,
+                conditional_styles: Vec::new(),
+                node_ref: None,
+                tabindex: None,
+                inline_errors: true,
+                native_validation: None,
+            },
+            validity(control_states),
+        );
+
+        let form_ref = create_node_ref::<html::Form>();
+        let novalidate = self.novalidate;
+        let on_keydown = Self::submit_shortcut(self.submit_on_ctrl_enter, form_ref);
+        let scroll_to_error = self.scroll_to_error;
+        let validation_gate = self.validation_gate.clone();
+        let confirm_fn = self.confirm_fn;
+        let on_submit = move |ev: SubmitEvent| {
+            if ev.default_prevented() {
+                return;
+            }
+            ev.prevent_default();
+            if !Self::controls_valid(&validation_cbs, &validation_gate, fd) {
+                set_submit_failed.set(true);
+                if scroll_to_error {
+                    Self::scroll_to_first_error(control_states);
+                }
+                return;
+            }
+            set_submit_failed.set(false);
+            if !Self::confirmed(&confirm_fn, fd) {
+                ev.prevent_default();
+                return;
+            }
+            on_submit(ev, fd);
+
+            let Some(form) = form_ref.get_untracked() else {
+                return;
+            };
+            if let Ok(form_data) = FormData::new_with_form(&form) {
+                action.dispatch(form_data);
+            }
+        };
+
+        let view = view! {
+            <form
+                node_ref=form_ref
+                method="post"
+                enctype="multipart/form-data"
+                novalidate=novalidate
+                on:submit=on_submit
+                on:keydown=on_keydown
+            >
+                {error_summary}
+                {elements}
+            </form>
+        }
+        .into_view();
+
+        Form {
+            fd,
+            validations: Self::gate_validations(self.validations, self.validation_gate),
             view,
+            control_states,
+            node_ref: form_ref,
         }
     }
 
@@ -453,41 +1453,80 @@ impl<FD: FormToolData> FormBuilder<FD> {
     ) -> Form<FD> {
         let fd = create_rw_signal(fd);
         let fs = Rc::new(fs);
+        let render_state = self.render_state(Signal::derive(|| false));
+        let control_states = render_state.control_states;
 
         let (views, validation_cbs): (Vec<_>, Vec<_>) = self
             .render_fns
             .into_iter()
-            .map(|r_fn| r_fn(fs.clone(), fd))
+            .map(|r_fn| r_fn(fs.clone(), fd, render_state.clone()))
             .unzip();
 
-        let elements = fs.form_frame(ControlRenderData {
-            data: views.into_view(),
-            styles: self.styles,
-        });
+        let (submit_failed, set_submit_failed) = create_signal(false);
+        let error_summary =
+            Self::error_summary(&fs, self.error_display, control_states, submit_failed);
+        let elements = fs.form_frame(
+            ControlRenderData {
+                data: views.into_view(),
+                styles: self.styles,
+                conditional_styles: Vec::new(),
+                node_ref: None,
+                tabindex: None,
+                inline_errors: true,
+                native_validation: None,
+            },
+            validity(control_states),
+        );
 
+        let scroll_to_error = self.scroll_to_error;
+        let validation_gate = self.validation_gate.clone();
+        let confirm_fn = self.confirm_fn;
         let on_submit = move |ev: SubmitEvent| {
             if ev.default_prevented() {
                 return;
             }
-            for validation in validation_cbs.iter().flatten() {
-                if !validation() {
-                    ev.prevent_default();
-                    return;
+            if !Self::controls_valid(&validation_cbs, &validation_gate, fd) {
+                set_submit_failed.set(true);
+                ev.prevent_default();
+                if scroll_to_error {
+                    Self::scroll_to_first_error(control_states);
                 }
+                return;
+            }
+            set_submit_failed.set(false);
+            if !Self::confirmed(&confirm_fn, fd) {
+                ev.prevent_default();
+                return;
             }
             on_submit(ev, fd);
         };
 
+        let form_ref = create_node_ref::<html::Form>();
+        let novalidate = self.novalidate;
+        let method = self.method.as_str();
+        let accept_charset = self.accept_charset;
+        let on_keydown = Self::submit_shortcut(self.submit_on_ctrl_enter, form_ref);
         let view = view! {
-            <Form action=url on:submit=on_submit>
+            <Form
+                action=url
+                method=method
+                on:submit=on_submit
+                on:keydown=on_keydown
+                node_ref=form_ref
+                attr:novalidate=novalidate
+                attr:accept-charset=accept_charset
+            >
+                {error_summary}
                 {elements}
             </Form>
         };
 
         Form {
             fd,
-            validations: self.validations,
+            validations: Self::gate_validations(self.validations, self.validation_gate),
             view,
+            control_states,
+            node_ref: form_ref,
         }
     }
 
@@ -495,29 +1534,188 @@ impl<FD: FormToolData> FormBuilder<FD> {
     pub(crate) fn build_form_controls(self, fd: FD, fs: FD::Style) -> Form<FD> {
         let fd = create_rw_signal(fd);
         let fs = Rc::new(fs);
+        let render_state = self.render_state(Signal::derive(|| false));
+        let control_states = render_state.control_states;
 
         let (views, _validation_cbs): (Vec<_>, Vec<_>) = self
             .render_fns
             .into_iter()
-            .map(|r_fn| r_fn(fs.clone(), fd))
+            .map(|r_fn| r_fn(fs.clone(), fd, render_state.clone()))
             .unzip();
 
-        let view = fs.form_frame(ControlRenderData {
-            data: views.into_view(),
-            styles: self.styles,
-        });
+        let view = fs.form_frame(
+            ControlRenderData {
+                data: views.into_view(),
+                styles: self.styles,
+                conditional_styles: Vec::new(),
+                node_ref: None,
+                tabindex: None,
+                inline_errors: true,
+                native_validation: None,
+            },
+            validity(control_states),
+        );
 
         Form {
             fd,
-            validations: self.validations,
+            validations: Self::gate_validations(self.validations, self.validation_gate),
             view,
+            control_states,
+            node_ref: create_node_ref(),
         }
     }
 
+    /// Renders the summary of the form's error messages, if the form shows
+    /// one.
+    ///
+    /// The summary is empty until `submit_failed` is set.
+    fn error_summary(
+        fs: &FD::Style,
+        error_display: ErrorDisplay,
+        control_states: StoredValue<Vec<ControlState>>,
+        submit_failed: ReadSignal<bool>,
+    ) -> Option<View> {
+        if !error_display.summary() {
+            return None;
+        }
+        let errors = Signal::derive(move || {
+            if !submit_failed.get() {
+                return Vec::new();
+            }
+            control_states.with_value(|states| {
+                states
+                    .iter()
+                    .filter_map(|state| state.validation_state.get().take_msg())
+                    .collect()
+            })
+        });
+        Some(fs.error_summary(errors))
+    }
+
+    /// Checks if submitting the form is confirmed by the form's
+    /// [`confirm_submit`](Self::confirm_submit) function, if it has one.
+    fn confirmed(confirm_fn: &Option<Rc<dyn ConfirmFn<FD>>>, fd: RwSignal<FD>) -> bool {
+        match confirm_fn {
+            Some(confirm_fn) => fd.with_untracked(|fd| confirm_fn(fd)),
+            None => true,
+        }
+    }
+
+    /// Creates the keydown handler for the form, which submits it on
+    /// Ctrl/Cmd+Enter if `enabled`.
+    fn submit_shortcut(
+        enabled: bool,
+        form_ref: NodeRef<html::Form>,
+    ) -> impl Fn(ev::KeyboardEvent) + 'static {
+        move |ev| {
+            if !enabled || ev.key() != "Enter" || !(ev.ctrl_key() || ev.meta_key()) {
+                return;
+            }
+            let Some(form) = form_ref.get_untracked() else {
+                return;
+            };
+            ev.prevent_default();
+            // this fires the submit event, so the form's validation runs
+            let _ = form.request_submit();
+        }
+    }
+
+    /// Runs all the validation callbacks of the controls, returning whether
+    /// they all pass.
+    ///
+    /// If the form's validation gate returns false, the callbacks are
+    /// skipped and the controls are considered valid.
+    fn controls_valid(
+        validation_cbs: &[Option<Box<dyn ValidationCb>>],
+        validation_gate: &Option<Rc<dyn CheckFn<FD>>>,
+        fd: RwSignal<FD>,
+    ) -> bool {
+        if let Some(gate) = validation_gate {
+            if !fd.with_untracked(|fd| gate(fd)) {
+                return true;
+            }
+        }
+        let mut valid = true;
+        for validation in validation_cbs.iter().flatten() {
+            // run every validation, so that all the errors are shown
+            valid &= validation();
+        }
+        valid
+    }
+
+    /// Scrolls the first control with a parse or validation error into view.
+    fn scroll_to_first_error(control_states: StoredValue<Vec<ControlState>>) {
+        let element = control_states.with_value(|states| {
+            states
+                .iter()
+                .find(|state| state.validation_state.get_untracked().is_err())
+                .and_then(|state| state.node_ref.get_untracked())
+        });
+        if let Some(element) = element {
+            element.scroll_into_view();
+        }
+    }
+
+    /// Wraps the validations so that they succeed while the `gate`
+    /// function returns false.
+    pub(crate) fn gate_validations(
+        validations: Vec<Rc<dyn FieldsValidationFn<FD>>>,
+        gate: Option<Rc<dyn CheckFn<FD>>>,
+    ) -> Vec<Rc<dyn FieldsValidationFn<FD>>> {
+        let Some(gate) = gate else {
+            return validations;
+        };
+        validations
+            .into_iter()
+            .map(|validation| {
+                let gate = gate.clone();
+                let validation = move |fd: &FD| {
+                    if !gate(fd) {
+                        return Ok(());
+                    }
+                    validation(fd)
+                };
+                Rc::new(validation) as Rc<dyn FieldsValidationFn<FD>>
+            })
+            .collect()
+    }
+
     /// Creates a [`FormValidator`] from this builder.
     pub(crate) fn validator(&self) -> FormValidator<FD> {
         FormValidator {
-            validations: self.validations.clone(),
+            validations: Self::gate_validations(
+                self.validations.clone(),
+                self.validation_gate.clone(),
+            ),
+        }
+    }
+
+    /// Creates the form wide state for rendering the form.
+    ///
+    /// `submitting` is whether the form is being submitted.
+    fn render_state(&self, submitting: Signal<bool>) -> RenderState<FD> {
+        RenderState {
+            control_states: store_value(Vec::new()),
+            mode: self.mode,
+            error_display: self.error_display,
+            on_field_change: self.on_field_change.clone(),
+            wrap_each: self.wrap_each.clone(),
+            parse_error_fn: self.parse_error_fn.clone(),
+            submitting,
+            native_validation: self.native_validation,
+        }
+    }
+
+    pub(crate) fn simulator(&self) -> FormSimulator<FD> {
+        FormSimulator {
+            simulators: self.simulators.clone(),
+            state: SimulateState {
+                parse_error_fn: self.parse_error_fn.clone(),
+                validations: Self::gate_validations(
+                    self.validations.clone(),
+                    self.validation_gate.clone(),
+                ),
+            },
         }
     }
 }
@@ -9,6 +9,12 @@ mod form_builder;
 pub mod styles;
 mod validation_builder;
 
-pub use form::{Form, FormToolData, FormValidator};
+pub use form::{
+    ErrorDisplay, Form, FormMethod, FormMode, FormSimulator, FormToolData, FormValidator,
+    MultipartAction,
+};
 pub use form_builder::FormBuilder;
-pub use validation_builder::ValidationBuilder;
+pub use validation_builder::{NativeValidation, ValidationBuilder};
+
+#[cfg(feature = "derive")]
+pub use leptos_form_tool_derive::FormOptions;
@@ -1,24 +1,88 @@
 use super::FormStyle;
 use crate::controls::{
-    button::ButtonData, checkbox::CheckboxData, heading::HeadingData, hidden::HiddenData,
-    output::OutputData, radio_buttons::RadioButtonsData, select::SelectData, slider::SliderData,
-    spacer::SpacerData, stepper::StepperData, submit::SubmitData, text_area::TextAreaData,
-    text_input::TextInputData, ControlRenderData, UpdateEvent, ValidationState,
+    button::ButtonData,
+    change_summary::{ChangeSummaryData, FieldChange},
+    checkbox::CheckboxData,
+    display::DisplayData,
+    group::{AccordionSectionData, TitledGroupData},
+    heading::HeadingData,
+    hidden::HiddenData,
+    output::OutputData,
+    progress::ProgressData,
+    radio_buttons::{RadioButtonsData, RadioStyle},
+    select::SelectData,
+    slider::SliderData,
+    spacer::SpacerData,
+    stepper::StepperData,
+    submit::SubmitData,
+    text_area::TextAreaData,
+    text_input::TextInputData,
+    ControlRenderData, UpdateEvent, ValidationState,
 };
+use crate::validation_builder::NativeValidation;
 use leptos::*;
 use std::rc::Rc;
-use web_sys::MouseEvent;
+use web_sys::{
+    js_sys::Function,
+    wasm_bindgen::{closure::Closure, JsCast},
+    MouseEvent,
+};
+
+/// The value of the select's "other" option.
+const OTHER_OPTION_VALUE: &str = "__other__";
 
-/// Styling attributes for the [`GridFormStyle`].
+/// Styling attributes for the [`GridFormStyle`](struct@GridFormStyle).
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum GFStyleAttr {
     /// Set the width of the control out of 12.
     /// Defaults to 12/12 (full width).
     Width(u32),
+    /// Sets the column that the control starts in, starting at 1.
+    ///
+    /// By default, controls flow into the next available column. This can
+    /// be used to leave a gap before a control, like right aligning a
+    /// submit button.
+    ColumnStart(u32),
+    /// Sets the CSS `order` of the control within the grid.
+    ///
+    /// Controls with a lower order are shown first, and controls default
+    /// to an order of 0. This changes where the control is shown without
+    /// changing the tab order or the order read by screen readers, which
+    /// follow the order the controls were added in.
+    Order(i32),
     /// Adds a tooltip to the control.
     /// This sets the html title attribute, which shows the text when the
     /// user hovers their mouse over the control for a couple seconds.
     Tooltip(String),
+    /// Adds an extra class to the control's parent element, or the form's
+    /// grid when applied to the form.
+    Class(String),
+    /// Sets the number of columns in the grid.
+    ///
+    /// This only applies to the form or a group.
+    Columns(u32),
+    /// Sets the gap between the rows and columns of the grid.
+    ///
+    /// This is a string to allow different units like "10px" or "1.25em".
+    /// This only applies to the form or a group.
+    Gap(String),
+    /// Makes the grid responsive, fitting as many columns of at least this
+    /// width as there is room for.
+    ///
+    /// This is a string to allow different units like "200px" or "15em".
+    /// On narrow screens, like phones, this collapses to a single column
+    /// without any media queries. Each control takes up one column, so the
+    /// [`Width`](GFStyleAttr::Width), [`ColumnStart`](GFStyleAttr::ColumnStart),
+    /// and [`Columns`](GFStyleAttr::Columns) attributes are ignored.
+    /// This only applies to the form or a group.
+    MinColumnWidth(String),
+    /// Adds an extra class to the control's parent element while the
+    /// control has a parse or validation error.
+    ///
+    /// This allows custom error styling, like a shake animation, on top of
+    /// the built in `form_input_invalid` class. This only applies to
+    /// controls that are validated.
+    InvalidClass(String),
 }
 
 /// A complete useable example for defining a form style.
@@ -28,53 +92,585 @@ pub enum GFStyleAttr {
 /// copy `grid_form.scss` from the git repo and put that in the `styles`
 /// directory for your leptos project to get all the styling.
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct GridFormStyle;
+pub struct GridFormStyle {
+    /// Whether the labels wrap their inputs, rather than being associated
+    /// with them by id.
+    wrap_labels: bool,
+}
+
+/// The default [`GridFormStyle`](struct@GridFormStyle).
+///
+/// This keeps `GridFormStyle` usable as a value, like it was when it was a
+/// unit struct.
+#[allow(non_upper_case_globals)]
+pub const GridFormStyle: GridFormStyle = GridFormStyle { wrap_labels: false };
 
 impl GridFormStyle {
+    /// Wraps the inputs of the controls in their labels, rather than
+    /// associating the labels with the inputs using the `for` attribute.
+    ///
+    /// This makes clicking the label text focus the input more reliably
+    /// across browsers and assistive technologies. Checkboxes are always
+    /// wrapped. Controls with more than one input, like radio buttons and
+    /// searchable selects, still use the `for` attribute.
+    pub fn wrap_labels(mut self) -> Self {
+        self.wrap_labels = true;
+        self
+    }
+
     fn common_component(
         &self,
         styles: &[<GridFormStyle as FormStyle>::StylingAttributes],
+        conditional_styles: &[(
+            Signal<bool>,
+            <GridFormStyle as FormStyle>::StylingAttributes,
+        )],
+        parent_class: &'static str,
+        inner: View,
+    ) -> View {
+        let styles = styles.to_vec();
+        let conditional_styles = conditional_styles.to_vec();
+        let attributes = create_memo(move |_| {
+            let mut width = 12;
+            let mut column_start = None;
+            let mut order = None;
+            let mut tooltip = None;
+            let mut class = parent_class.to_string();
+            let active_styles = conditional_styles
+                .iter()
+                .filter(|(when, _)| when.get())
+                .map(|(_, style)| style);
+            for style in styles.iter().chain(active_styles) {
+                match style {
+                    GFStyleAttr::Width(w) => width = *w,
+                    GFStyleAttr::ColumnStart(c) => column_start = Some(*c),
+                    GFStyleAttr::Order(o) => order = Some(*o),
+                    GFStyleAttr::Tooltip(t) => tooltip = Some(t.clone()),
+                    GFStyleAttr::Class(c) => {
+                        class.push(' ');
+                        class.push_str(c);
+                    }
+                    _ => {}
+                }
+            }
+
+            let grid_column = match column_start {
+                Some(start) => format!("{} / span {}", start, width),
+                None => format!("span {}", width),
+            };
+            (class, grid_column, tooltip, order)
+        });
+
+        view! {
+            <div
+                class=move || attributes.with(|a| a.0.clone())
+                style:grid-column=move || attributes.with(|a| a.1.clone())
+                style:order=move || attributes.with(|a| a.3.map(|o| o.to_string()))
+                title=move || attributes.with(|a| a.2.clone())
+            >
+                {inner}
+            </div>
+        }
+        .into_view()
+    }
+
+    /// Creates a visually hidden live region that announces `pending_text`
+    /// to screen readers while `pending` is true.
+    fn pending_announcement(pending: MaybeSignal<bool>, pending_text: String) -> View {
+        view! {
+            <span class="form_sr_only" role="status" aria-live="polite">
+                {move || pending.get().then(|| pending_text.clone())}
+            </span>
+        }
+        .into_view()
+    }
+
+    /// Like [`common_component`](Self::common_component), but also applies
+    /// the [`InvalidClass`](GFStyleAttr::InvalidClass) styles while the
+    /// control has an error.
+    fn validated_component(
+        &self,
+        styles: &[<GridFormStyle as FormStyle>::StylingAttributes],
+        conditional_styles: &[(
+            Signal<bool>,
+            <GridFormStyle as FormStyle>::StylingAttributes,
+        )],
+        validation_state: Signal<ValidationState>,
         parent_class: &'static str,
         inner: View,
     ) -> View {
-        let mut width = 12;
-        let mut tooltip = None;
+        let is_err = move || validation_state.get().is_err();
+        let always = Signal::derive(|| true);
+        let invalid_styles = styles
+            .iter()
+            .map(|style| (always, style))
+            .chain(
+                conditional_styles
+                    .iter()
+                    .map(|(when, style)| (*when, style)),
+            )
+            .filter_map(|(when, style)| match style {
+                GFStyleAttr::InvalidClass(c) => Some((
+                    Signal::derive(move || when.get() && is_err()),
+                    GFStyleAttr::Class(c.clone()),
+                )),
+                _ => None,
+            });
+        let conditional_styles = conditional_styles
+            .iter()
+            .cloned()
+            .chain(invalid_styles)
+            .collect::<Vec<_>>();
+        self.common_component(styles, &conditional_styles, parent_class, inner)
+    }
+
+    /// Binds the element to the control's node ref, if it has one.
+    fn bind_node_ref<El: html::ElementDescriptor + 'static>(
+        element: HtmlElement<El>,
+        node_ref: Option<NodeRef<html::AnyElement>>,
+    ) -> HtmlElement<html::AnyElement> {
+        let element = element.into_any();
+        match node_ref {
+            Some(node_ref) => element.node_ref(node_ref),
+            None => element,
+        }
+    }
+
+    /// Shows the unit after the input, if the control has one.
+    fn with_unit(input: HtmlElement<html::AnyElement>, unit: &Option<String>) -> View {
+        match unit {
+            Some(unit) => view! {
+                <div class="form_unit_input">
+                    {input}
+                    <span class="form_unit">{unit.clone()}</span>
+                </div>
+            }
+            .into_view(),
+            None => input.into_view(),
+        }
+    }
+
+    /// Adds a hidden input that sends the `submitted_value` with the form, if
+    /// there is one.
+    ///
+    /// The visible input should not be named in this case, so that only the
+    /// submitted value is sent.
+    fn with_submitted_value(
+        input: View,
+        name: &str,
+        submitted_value: Option<Signal<String>>,
+    ) -> View {
+        match submitted_value {
+            Some(submitted_value) => view! {
+                {input}
+                <input type="hidden" name=name.to_string() prop:value=move || submitted_value.get()/>
+            }
+            .into_view(),
+            None => input,
+        }
+    }
+
+    /// Makes the text area grow to fit its value, up to `max_rows` lines.
+    fn auto_grow(
+        input: HtmlElement<html::Textarea>,
+        max_rows: u32,
+        value_getter: Signal<String>,
+    ) -> HtmlElement<html::Textarea> {
+        let element = input.clone();
+        create_effect(move |_| {
+            value_getter.track();
+            let element = element.clone();
+            // wait for the value to be set and the text area to be laid out
+            request_animation_frame(move || Self::grow_text_area(&element, max_rows));
+        });
+        input.on(ev::input, move |ev| {
+            Self::grow_text_area(&event_target(&ev), max_rows)
+        })
+    }
+
+    /// Sets the height of the text area to fit its value, but no taller than
+    /// `max_rows` lines.
+    fn grow_text_area(element: &web_sys::HtmlTextAreaElement, max_rows: u32) {
+        let style = element.style();
+        let _ = style.set_property("height", "auto");
+        // measure the height of the text area at its maximum size
+        let rows = element.rows();
+        element.set_rows(max_rows.max(rows));
+        let max_height = element.offset_height();
+        element.set_rows(rows);
+        // the scroll height does not include the borders
+        let borders = element.offset_height() - element.client_height();
+        let height = element.scroll_height() + borders;
+        let _ = style.set_property("height", &format!("{}px", height.min(max_height)));
+        let overflow = if height > max_height {
+            "auto"
+        } else {
+            "hidden"
+        };
+        let _ = style.set_property("overflow-y", overflow);
+    }
+
+    /// Sets the control's native validation attributes on the element, if
+    /// it has any.
+    fn with_native_validation<El: html::ElementDescriptor + 'static>(
+        element: HtmlElement<El>,
+        native_validation: &Option<NativeValidation>,
+    ) -> HtmlElement<El> {
+        let Some(native_validation) = native_validation else {
+            return element;
+        };
+        let mut element = element;
+        if native_validation.required {
+            element = element.attr("required", true);
+        }
+        if let Some(min_len) = native_validation.min_len {
+            element = element.attr("minlength", min_len.to_string());
+        }
+        if let Some(max_len) = native_validation.max_len {
+            element = element.attr("maxlength", max_len.to_string());
+        }
+        if let Some(ref min) = native_validation.min {
+            element = element.attr("min", min.clone());
+        }
+        if let Some(ref max) = native_validation.max {
+            element = element.attr("max", max.clone());
+        }
+        if let Some(ref pattern) = native_validation.pattern {
+            element = element.attr("pattern", pattern.clone());
+        }
+        element
+    }
+
+    /// Renders the text input for a choice control's "other" option, which
+    /// is only shown while `active` is true.
+    fn other_input(
+        &self,
+        name: &str,
+        active: Signal<bool>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
+    ) -> View {
+        let name = name.to_string();
+        (move || {
+            active.get().then(|| {
+                view! {
+                    <input
+                        type="text"
+                        name=name.clone()
+                        class="form_input form_other_input"
+                        class=("form_input_invalid", move || validation_state.get().is_err())
+                        class=(
+                            "form_input_valid",
+                            move || touched.get() && validation_state.get().is_passed(),
+                        )
+                        prop:value=move || value_getter.get()
+                        on:input=move |ev| {
+                            value_setter.set(event_target_value(&ev));
+                        }
+                    />
+                }
+            })
+        })
+        .into_view()
+    }
+
+    /// Renders a grid of the given inner view, applying the
+    /// [`Columns`](GFStyleAttr::Columns) and [`Gap`](GFStyleAttr::Gap)
+    /// styles.
+    fn grid(
+        &self,
+        styles: &[<GridFormStyle as FormStyle>::StylingAttributes],
+        grid_class: &'static str,
+        inner: View,
+    ) -> HtmlElement<html::Div> {
+        let mut class = grid_class.to_string();
+        let mut columns = None;
+        let mut gap = None;
+        let mut min_column_width = None;
         for style in styles.iter() {
             match style {
-                GFStyleAttr::Width(w) => width = *w,
-                GFStyleAttr::Tooltip(t) => tooltip = Some(t),
+                GFStyleAttr::Class(c) => {
+                    class.push(' ');
+                    class.push_str(c);
+                }
+                GFStyleAttr::Columns(c) => columns = Some(format!("repeat({}, minmax(0, 1fr))", c)),
+                GFStyleAttr::Gap(g) => gap = Some(g.clone()),
+                GFStyleAttr::MinColumnWidth(w) => min_column_width = Some(w.clone()),
+                _ => {}
             }
         }
+        if let Some(width) = min_column_width {
+            class.push_str(" form_grid_responsive");
+            columns = Some(format!(
+                "repeat(auto-fit, minmax(min({}, 100%), 1fr))",
+                width
+            ));
+        }
 
         view! {
-            <div class=parent_class style:grid-column=format!("span {}", width) title=tooltip>
+            <div class=class style:grid-template-columns=columns style:gap=gap>
                 {inner}
             </div>
         }
+    }
+
+    /// Renders the header of the control followed by its input, or the
+    /// input wrapped in its label if [`wrap_labels`](Self::wrap_labels) is
+    /// set.
+    fn labeled_control(
+        &self,
+        name: &str,
+        label: Option<&MaybeSignal<String>>,
+        validation_state: Signal<ValidationState>,
+        inline_errors: bool,
+        input: View,
+    ) -> View {
+        let label = match label {
+            Some(label) if self.wrap_labels => label.clone(),
+            _ => {
+                return view! {
+                    {self.control_header(name, label, validation_state, inline_errors)}
+                    {input}
+                }
+                .into_view()
+            }
+        };
+
+        let msg = move || validation_state.get().take_msg().filter(|_| inline_errors);
+        view! {
+            <label class="form_label_wrap">
+                <div>
+                    <span class="form_label">{label}</span>
+                    <span
+                        class="form_error"
+                        class=("form_parse_error", move || validation_state.get().is_parse_err())
+                    >
+                        {msg}
+                    </span>
+                </div>
+                {input}
+            </label>
+        }
+        .into_view()
+    }
+
+    /// Renders the label and error message above a control.
+    ///
+    /// If the control has no label, this only renders when there is an
+    /// error to show, so label-less controls don't leave an empty gap.
+    fn control_header(
+        &self,
+        name: &str,
+        label: Option<&MaybeSignal<String>>,
+        validation_state: Signal<ValidationState>,
+        inline_errors: bool,
+    ) -> View {
+        let msg = move || validation_state.get().take_msg().filter(|_| inline_errors);
+        let Some(label) = label else {
+            return (move || {
+                msg().map(|msg| {
+                    view! {
+                        <div>
+                            <span
+                                class="form_error"
+                                class=("form_parse_error", move || validation_state.get().is_parse_err())
+                            >
+                                {msg}
+                            </span>
+                        </div>
+                    }
+                })
+            })
+            .into_view();
+        };
+
+        view! {
+            <div>
+                <label for=name.to_string() class="form_label">
+                    {label.clone()}
+                </label>
+                <span
+                    class="form_error"
+                    class=("form_parse_error", move || validation_state.get().is_parse_err())
+                >
+                    {msg}
+                </span>
+            </div>
+        }
         .into_view()
     }
 }
 impl FormStyle for GridFormStyle {
     type StylingAttributes = GFStyleAttr;
 
-    fn form_frame(&self, form: ControlRenderData<Self, View>) -> View {
-        view! { <div class="form_grid">{form.data}</div> }.into_view()
+    fn form_frame(&self, form: ControlRenderData<Self, View>, is_valid: Signal<bool>) -> View {
+        self.grid(&form.styles, "form_grid", form.data)
+            .class("form_has_errors", move || !is_valid.get())
+            .into_view()
+    }
+
+    fn error_summary(&self, errors: Signal<Vec<String>>) -> View {
+        (move || {
+            let errors = errors.get();
+            (!errors.is_empty()).then(|| {
+                view! {
+                    <div class="form_error_summary" role="alert">
+                        <ul>
+                            {errors
+                                .into_iter()
+                                .map(|error| view! { <li>{error}</li> })
+                                .collect_view()}
+                        </ul>
+                    </div>
+                }
+            })
+        })
+        .into_view()
     }
 
     /// A common function that wraps the given view in the styles
     fn custom_component(&self, styles: &[Self::StylingAttributes], inner: View) -> View {
-        self.common_component(styles, "custom_component_parent", inner)
+        self.common_component(styles, &[], "custom_component_parent", inner)
+    }
+
+    fn details_help(&self, control: View, summary: String, content: String) -> View {
+        let details = view! {
+            <details class="form_details_help">
+                <summary>{summary}</summary>
+                <p>{content}</p>
+            </details>
+        };
+        // put the help inside the control's parent so it stays in the
+        // control's grid cell
+        match control {
+            View::Element(element) => element.into_html_element().child(details).into_view(),
+            control => view! { {control} {details} }.into_view(),
+        }
     }
 
     fn group(&self, group: Rc<ControlRenderData<Self, View>>) -> View {
-        let view = view! { <div class="form_group form_grid">{&group.data}</div> }.into_view();
+        // the extra class goes on the group's parent, not the inner grid
+        let grid_styles = group
+            .styles
+            .iter()
+            .filter(|s| !matches!(s, GFStyleAttr::Class(_)))
+            .cloned()
+            .collect::<Vec<_>>();
+        let view = self
+            .grid(&grid_styles, "form_group form_grid", group.data.clone())
+            .into_view();
+
+        self.common_component(
+            &group.styles,
+            &group.conditional_styles,
+            "group_parent",
+            view,
+        )
+    }
+
+    fn titled_group(&self, group: Rc<ControlRenderData<Self, TitledGroupData>>) -> View {
+        let error_count = group.data.error_count;
+        let error_text = move || match error_count.get() {
+            0 => None,
+            1 => Some(String::from("(1 error)")),
+            n => Some(format!("({} errors)", n)),
+        };
+        let header = view! {
+            <div class="group_header">
+                <h2 class="form_heading">{group.data.title.clone()}</h2>
+                <span class="group_error_count">{error_text}</span>
+            </div>
+        };
+        let inner = (header, group.data.inner.clone()).into_view();
+
+        let grid_styles = group
+            .styles
+            .iter()
+            .filter(|s| !matches!(s, GFStyleAttr::Class(_)))
+            .cloned()
+            .collect::<Vec<_>>();
+        let view = self
+            .grid(&grid_styles, "form_group form_grid", inner)
+            .into_view();
+
+        self.common_component(
+            &group.styles,
+            &group.conditional_styles,
+            "group_parent",
+            view,
+        )
+    }
+
+    fn accordion_section(
+        &self,
+        section: Rc<ControlRenderData<Self, AccordionSectionData>>,
+    ) -> View {
+        let error_count = section.data.error_count;
+        let error_text = move || match error_count.get() {
+            0 => None,
+            1 => Some(String::from("(1 error)")),
+            n => Some(format!("({} errors)", n)),
+        };
+        let open = section.data.open;
+        let set_open = section.data.set_open;
+        let header = view! {
+            <button
+                type="button"
+                class="group_header form_accordion_header"
+                aria-expanded=move || open.get().to_string()
+                on:click=move |_| set_open.set(!open.get_untracked())
+            >
+                <h2 class="form_heading">{section.data.title.clone()}</h2>
+                <span class="group_error_count">{error_text}</span>
+            </button>
+        };
 
-        self.common_component(&group.styles, "group_parent", view)
+        let grid_styles = section
+            .styles
+            .iter()
+            .filter(|s| !matches!(s, GFStyleAttr::Class(_)))
+            .cloned()
+            .collect::<Vec<_>>();
+        // open the section when the browser finds one of its inputs invalid,
+        // as the browser can't show the error on a hidden input, which
+        // blocks submitting the form
+        let inner_ref = create_node_ref::<html::Div>();
+        inner_ref.on_load(move |inner| {
+            let open_section = Closure::<dyn Fn()>::new(move || set_open.set(true));
+            let listener = open_section.as_ref().unchecked_ref::<Function>().clone();
+            // the invalid event doesn't bubble, so it has to be captured
+            let _ = inner.add_event_listener_with_callback_and_bool("invalid", &listener, true);
+            on_cleanup(move || {
+                let _ =
+                    inner.remove_event_listener_with_callback_and_bool("invalid", &listener, true);
+                drop(open_section);
+            });
+        });
+        // collapse with css so that the inputs are still sent with the form
+        let inner = view! {
+            <div node_ref=inner_ref style:display=move || (!open.get()).then_some("none")>
+                {self.grid(&grid_styles, "form_group form_grid", section.data.inner.clone())}
+            </div>
+        };
+        let view = view! {
+            <div class="form_accordion_section">{header} {inner}</div>
+        }
+        .into_view();
+
+        self.common_component(
+            &section.styles,
+            &section.conditional_styles,
+            "group_parent",
+            view,
+        )
     }
 
     fn spacer(&self, control: Rc<ControlRenderData<Self, SpacerData>>) -> View {
         self.common_component(
             &control.styles,
+            &control.conditional_styles,
             "spacer_parent",
             view! { <div style:height=control.data.height.as_ref()></div> }.into_view(),
         )
@@ -88,28 +684,56 @@ impl FormStyle for GridFormStyle {
         use crate::controls::heading::HeadingLevel::*;
 
         let title = move || value_getter.map(|v| v.get()).unwrap_or_default();
+        let data = control.data.clone();
+        let id = move || data.anchor_id(&title());
 
         let view = match control.data.level {
-            H1 => view! { <h1 class="form_heading"> {title} </h1> }.into_view(),
-            H2 => view! { <h2 class="form_heading"> {title} </h2> }.into_view(),
-            H3 => view! { <h3 class="form_heading"> {title} </h3> }.into_view(),
-            H4 => view! { <h4 class="form_heading"> {title} </h4> }.into_view(),
+            H1 => view! { <h1 id=id class="form_heading"> {title} </h1> }.into_view(),
+            H2 => view! { <h2 id=id class="form_heading"> {title} </h2> }.into_view(),
+            H3 => view! { <h3 id=id class="form_heading"> {title} </h3> }.into_view(),
+            H4 => view! { <h4 id=id class="form_heading"> {title} </h4> }.into_view(),
         };
 
-        self.common_component(&control.styles, "heading_parent", view)
+        self.common_component(
+            &control.styles,
+            &control.conditional_styles,
+            "heading_parent",
+            view,
+        )
     }
 
     fn submit(
         &self,
         control: Rc<ControlRenderData<Self, SubmitData>>,
         value_getter: Option<Signal<String>>,
+        pending: Signal<bool>,
     ) -> View {
         let title = move || value_getter.map(|v| v.get()).unwrap_or_default();
 
+        let pending_text = control
+            .data
+            .pending_text
+            .clone()
+            .unwrap_or_else(|| String::from("Submitting…"));
         self.common_component(
             &control.styles,
+            &control.conditional_styles,
             "submit_parent",
-            view! { <input type="submit" value=title class="form_submit"/> }.into_view(),
+            view! {
+                <input
+                    type="submit"
+                    value=title
+                    tabindex=control.tabindex
+                    formaction=control.data.formaction.clone()
+                    formmethod=control.data.formmethod.clone()
+                    class="form_submit"
+                    class:form_submit_pending=pending
+                    disabled=pending
+                    aria-busy=move || pending.get().to_string()
+                />
+                {Self::pending_announcement(pending.into(), pending_text)}
+            }
+            .into_view(),
         )
     }
 
@@ -127,14 +751,34 @@ impl FormStyle for GridFormStyle {
 
         let title = move || value_getter.map(|v| v.get()).unwrap_or_default();
 
+        let pending = control.data.pending;
+        let pending_text = control
+            .data
+            .pending_text
+            .clone()
+            .unwrap_or_else(|| String::from("Loading…"));
         let view = view! {
-            <button type="button" class="form_button" on:click=on_click>
+            <button
+                type="button"
+                tabindex=control.tabindex
+                class="form_button"
+                class:form_button_pending=pending
+                disabled=pending
+                aria-busy=move || pending.get().to_string()
+                on:click=on_click
+            >
                 {title}
             </button>
+            {Self::pending_announcement(pending, pending_text)}
         }
         .into_view();
 
-        self.common_component(&control.styles, "button_parent", view)
+        self.common_component(
+            &control.styles,
+            &control.conditional_styles,
+            "button_parent",
+            view,
+        )
     }
 
     fn output(
@@ -142,8 +786,122 @@ impl FormStyle for GridFormStyle {
         control: Rc<ControlRenderData<Self, OutputData>>,
         value_getter: Option<Signal<String>>,
     ) -> View {
-        let view = view! { <span>{move || value_getter.map(|g| g.get())}</span> }.into_view();
-        self.common_component(&control.styles, "output_parent", view)
+        let text = move || value_getter.map(|g| g.get()).unwrap_or_default();
+        let view = if control.data.list {
+            let items = move || {
+                text()
+                    .lines()
+                    .map(|line| view! { <li>{line.to_string()}</li> })
+                    .collect_view()
+            };
+            view! { <ul class="form_output_list">{items}</ul> }.into_view()
+        } else {
+            view! {
+                <span class:form_output_multiline=control.data.multiline>{text}</span>
+            }
+            .into_view()
+        };
+        self.common_component(
+            &control.styles,
+            &control.conditional_styles,
+            "output_parent",
+            view,
+        )
+    }
+
+    fn progress(
+        &self,
+        control: Rc<ControlRenderData<Self, ProgressData>>,
+        completion: Signal<f32>,
+    ) -> View {
+        let percent = move || format!("{:.0}%", completion.get() * 100.0);
+        let view = view! {
+            {control.data.label.as_ref().map(|label| {
+                view! {
+                    <div>
+                        <span class="form_label">{label.clone()}</span>
+                    </div>
+                }
+            })}
+            <div class="form_progress">
+                <progress max="1" value=move || completion.get()>{percent}</progress>
+                <span>{percent}</span>
+            </div>
+        }
+        .into_view();
+        self.common_component(
+            &control.styles,
+            &control.conditional_styles,
+            "progress_parent",
+            view,
+        )
+    }
+
+    fn change_summary(
+        &self,
+        control: Rc<ControlRenderData<Self, ChangeSummaryData>>,
+        changes: Signal<Vec<FieldChange>>,
+    ) -> View {
+        let view = view! {
+            {control.data.label.as_ref().map(|label| {
+                view! {
+                    <div>
+                        <span class="form_label">{label.clone()}</span>
+                    </div>
+                }
+            })}
+            <ul class="form_change_summary">
+                <For
+                    each=move || changes.get()
+                    key=|change| change.clone()
+                    children=|change| {
+                        view! {
+                            <li>
+                                <span class="form_change_name">{change.name}</span>
+                                ": "
+                                <span class="form_change_old">{change.old}</span>
+                                " → "
+                                <span class="form_change_new">{change.new}</span>
+                            </li>
+                        }
+                    }
+                />
+            </ul>
+        }
+        .into_view();
+        self.common_component(
+            &control.styles,
+            &control.conditional_styles,
+            "change_summary_parent",
+            view,
+        )
+    }
+
+    fn display(
+        &self,
+        control: Rc<ControlRenderData<Self, DisplayData>>,
+        value_getter: Signal<String>,
+    ) -> View {
+        let view = view! {
+            {control.data.label.as_ref().map(|label| {
+                view! {
+                    <div>
+                        <span class="form_label">{label.clone()}</span>
+                    </div>
+                }
+            })}
+            <span id=&control.data.name class="form_display">
+                {move || value_getter.get()}
+            </span>
+        }
+        .into_view();
+
+        self.common_component(
+            &control.styles,
+            &control.conditional_styles,
+            "display_parent",
+            view,
+        )
     }
 
     fn hidden(
@@ -168,15 +926,24 @@ impl FormStyle for GridFormStyle {
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View {
         let input = view! {
             <input
                 type=control.data.input_type
                 id=&control.data.name
+                tabindex=control.tabindex
                 name=&control.data.name
-                placeholder=control.data.placeholder.as_ref()
+                placeholder=control.data.placeholder.clone()
+                readonly=control.data.readonly
+                spellcheck=control.data.spellcheck.map(|s| s.to_string())
+                autocapitalize=control.data.autocapitalize.map(|a| a.as_str())
                 class="form_input"
                 class=("form_input_invalid", move || validation_state.get().is_err())
+                class=(
+                    "form_input_valid",
+                    move || touched.get() && validation_state.get().is_passed(),
+                )
                 prop:value=move || value_getter.get()
             />
         };
@@ -192,19 +959,43 @@ impl FormStyle for GridFormStyle {
                 value_setter.set(event_target_value(&ev));
             }),
         };
+        let input = Self::with_native_validation(input, &control.native_validation);
+        let input = Self::bind_node_ref(input, control.node_ref);
 
-        let view = view! {
-            <div>
-                <label for=&control.data.name class="form_label">
-                    {control.data.label.as_ref()}
-                </label>
-                <span class="form_error">{move || validation_state.get().take_msg()}</span>
-            </div>
-            {input}
-        }
-        .into_view();
+        let input = if control.data.clearable && !control.data.readonly {
+            let clear_button = move || {
+                (!value_getter.get().is_empty()).then(|| {
+                    view! {
+                        <button
+                            type="button"
+                            class="form_clear_button"
+                            on:click=move |_| value_setter.set(String::new())
+                        >
+                            "×"
+                        </button>
+                    }
+                })
+            };
+            view! { <div class="form_clearable">{input} {clear_button}</div> }.into_view()
+        } else {
+            input.into_view()
+        };
 
-        self.common_component(&control.styles, "text_input_parent", view)
+        let view = self.labeled_control(
+            &control.data.name,
+            control.data.label.as_ref(),
+            validation_state,
+            control.inline_errors,
+            input.into_view(),
+        );
+
+        self.validated_component(
+            &control.styles,
+            &control.conditional_styles,
+            validation_state,
+            "text_input_parent",
+            view,
+        )
     }
 
     fn text_area(
@@ -213,16 +1004,26 @@ impl FormStyle for GridFormStyle {
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View {
         let input = view! {
             <textarea
                 id=&control.data.name
+                tabindex=control.tabindex
                 name=&control.data.name
-                placeholder=control.data.placeholder.as_ref()
+                placeholder=control.data.placeholder.clone()
+                readonly=control.data.readonly
+                spellcheck=control.data.spellcheck.map(|s| s.to_string())
+                autocapitalize=control.data.autocapitalize.map(|a| a.as_str())
+                rows=control.data.rows
                 prop:value=move || value_getter.get()
                 style="resize: vertical;"
                 class="form_input"
                 class=("form_input_invalid", move || validation_state.get().is_err())
+                class=(
+                    "form_input_valid",
+                    move || touched.get() && validation_state.get().is_passed(),
+                )
             ></textarea>
         };
 
@@ -237,19 +1038,73 @@ impl FormStyle for GridFormStyle {
                 value_setter.set(event_target_value(&ev));
             }),
         };
+        let input = match control.data.max_rows {
+            Some(max_rows) => Self::auto_grow(input, max_rows, value_getter),
+            None => input,
+        };
+        let input = Self::with_native_validation(input, &control.native_validation);
+        let input = Self::bind_node_ref(input, control.node_ref);
 
-        let view = view! {
-            <div>
-                <label for=&control.data.name class="form_label">
-                    {control.data.label.as_ref()}
-                </label>
-                <span class="form_error">{move || validation_state.get().take_msg()}</span>
-            </div>
-            {input}
-        }
-        .into_view();
+        #[cfg(feature = "markdown")]
+        let input = match control.data.preview {
+            true => {
+                let previewing = create_rw_signal(false);
+                // keep the text area in the dom so that it is still sent with the form
+                let input = input.style("display", move || previewing.get().then_some("none"));
+                view! {
+                    <div class="form_preview_tabs">
+                        <button
+                            type="button"
+                            class="form_preview_tab"
+                            class=("form_preview_tab_active", move || !previewing.get())
+                            on:click=move |_| previewing.set(false)
+                        >
+                            "Edit"
+                        </button>
+                        <button
+                            type="button"
+                            class="form_preview_tab"
+                            class=("form_preview_tab_active", move || previewing.get())
+                            on:click=move |_| previewing.set(true)
+                        >
+                            "Preview"
+                        </button>
+                    </div>
+                    {input}
+                    <div
+                        class="form_preview"
+                        style:display=move || (!previewing.get()).then_some("none")
+                        inner_html=move || TextAreaData::preview_html(&value_getter.get())
+                    ></div>
+                }
+                .into_view()
+            }
+            false => input.into_view(),
+        };
+
+        let view = match control.data.preview {
+            // the preview tabs would be labeled instead of the text area
+            true => view! {
+                {self.control_header(&control.data.name, control.data.label.as_ref(), validation_state, control.inline_errors)}
+                {input}
+            }
+            .into_view(),
+            false => self.labeled_control(
+                &control.data.name,
+                control.data.label.as_ref(),
+                validation_state,
+                control.inline_errors,
+                input.into_view(),
+            ),
+        };
 
-        self.common_component(&control.styles, "text_area_parent", view)
+        self.validated_component(
+            &control.styles,
+            &control.conditional_styles,
+            validation_state,
+            "text_area_parent",
+            view,
+        )
     }
 
     fn radio_buttons(
@@ -258,54 +1113,133 @@ impl FormStyle for GridFormStyle {
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View {
+        // the "other" option is active when chosen by the user, or when the
+        // value does not match any of the options
+        let other_chosen = create_rw_signal(false);
+        let has_other = control.data.other_option.is_some();
+        let values = control
+            .data
+            .options
+            .iter()
+            .map(|(_, value)| value.clone())
+            .collect::<Vec<_>>();
+        let other_active = Signal::derive(move || {
+            has_other
+                && (other_chosen.get()
+                    || value_getter.with(|v| !v.is_empty() && !values.contains(v)))
+        });
+
         let buttons_view = control
             .data
             .options
             .iter()
             .map(|(display, value)| {
                 let display = display.clone();
+                let description = control.data.descriptions.get(value).map(|description| {
+                    view! { <span class="form_radio_description">{description.clone()}</span> }
+                });
                 let value = value.clone();
                 let value_clone = value.clone();
                 let value_clone2 = value.clone();
-                view! {
+                let checked = move || !other_active.get() && value_getter.get() == value_clone;
+                let input = view! {
                     <input
                         type="radio"
                         id=&value
+                        tabindex=control.tabindex
                         name=&control.data.name
                         value=&value
-                        prop:checked=move || { value_getter.get() == value_clone }
+                        prop:checked=checked.clone()
                         on:input=move |ev| {
                             let new_value = event_target_checked(&ev);
                             if new_value {
+                                other_chosen.set(false);
                                 value_setter.set(value_clone2.clone());
                             }
                         }
                     />
+                };
+                let input = Self::with_native_validation(input, &control.native_validation);
 
-                    <label for=&value>{display}</label>
-                    <br/>
+                match control.data.style {
+                    RadioStyle::Inline => view! {
+                        {input}
+                        <label for=&value>{display}</label>
+                        {description}
+                        <br/>
+                    }
+                    .into_view(),
+                    // the whole card is the label, so clicking anywhere selects it
+                    RadioStyle::Card => view! {
+                        <label class="form_radio_card" class:form_radio_card_selected=checked>
+                            {input}
+                            <span>{display}</span>
+                            {description}
+                        </label>
+                    }
+                    .into_view(),
                 }
             })
             .collect_view();
 
-        let view = view! {
-            <div>
-                <label for=&control.data.name class="form_label">
-                    {control.data.label.as_ref()}
-                </label>
-                <span class="form_error">{move || validation_state.get().take_msg()}</span>
-            </div>
+        // the "other" radio has no name so that only the text input's value
+        // is sent with the form
+        let other_view = control.data.other_option.as_ref().map(|display| {
+            let other_id = format!("{}_other", control.data.name);
+            view! {
+                <input
+                    type="radio"
+                    id=&other_id
+                    tabindex=control.tabindex
+                    prop:checked=other_active
+                    on:input=move |ev| {
+                        if event_target_checked(&ev) {
+                            other_chosen.set(true);
+                            value_setter.set(String::new());
+                        }
+                    }
+                />
+
+                <label for=&other_id>{display}</label>
+                <br/>
+                {self.other_input(
+                    &control.data.name,
+                    other_active,
+                    value_getter,
+                    value_setter,
+                    validation_state,
+                    touched,
+                )}
+            }
+        });
+
+        let buttons_view = view! {
             <div
                 class="form_input"
                 class:form_input_invalid=move || validation_state.get().is_err()
+                class:form_input_valid=move || touched.get() && validation_state.get().is_passed()
             >
                 {buttons_view}
+                {other_view}
             </div>
+        };
+        let buttons_view = Self::bind_node_ref(buttons_view, control.node_ref);
+
+        let view = view! {
+            {self.control_header(&control.data.name, control.data.label.as_ref(), validation_state, control.inline_errors)}
+            {buttons_view}
         }
         .into_view();
 
-        self.common_component(&control.styles, "radio_buttons_parent", view)
+        self.validated_component(
+            &control.styles,
+            &control.conditional_styles,
+            validation_state,
+            "radio_buttons_parent",
+            view,
+        )
     }
 
     fn select(
@@ -314,57 +1248,158 @@ impl FormStyle for GridFormStyle {
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View {
+        // the "other" option is active when chosen by the user, or when the
+        // value does not match any of the options
+        let other_chosen = create_rw_signal(false);
+        let has_other = control.data.other_option.is_some();
+        let blank_value = control.data.blank_value.clone();
+        let options = control.data.options.clone();
+        let other_active = Signal::derive(move || {
+            has_other
+                && (other_chosen.get()
+                    || value_getter.with(|v| {
+                        *v != blank_value && options.with(|o| o.iter().all(|(_, value)| value != v))
+                    }))
+        });
+
         let control_clone = control.clone();
         let options_view = move || {
             control_clone
-            .data
-            .options
-            .get()
-            .iter()
-            .map(|(display, value)| {
-                let display = display.clone();
-                let value = value.clone();
-                view! {
-                    <option value=value.clone() selected=move || { value_getter.get() == *value }>
-                        {display}
-                    </option>
-                }
-            })
-            .collect_view()
+                .data
+                .options
+                .get()
+                .iter()
+                .map(|(display, value)| {
+                    let display = display.clone();
+                    let value = value.clone();
+                    view! {
+                        <option
+                            value=value.clone()
+                            selected=move || !other_active.get() && value_getter.get() == *value
+                        >
+                            {display}
+                        </option>
+                    }
+                })
+                .collect_view()
+        };
+
+        let loading = control.data.loading;
+        let load_error = control.data.load_error.clone();
+        let status_option_view = move || {
+            if loading.get() {
+                return Some(view! { <option disabled=true>"Loading…"</option> });
+            }
+            load_error
+                .get()
+                .map(|error| view! { <option disabled=true>{error}</option> })
         };
 
+        let blank_value = control.data.blank_value.clone();
         let blank_option_view = control.data.blank_option.as_ref().map(|display| {
             view! {
-                <option value="" selected=move || { value_getter.get().as_str() == "" }>
+                <option
+                    value=blank_value.clone()
+                    selected=move || {
+                        !other_active.get() && value_getter.with(|v| *v == blank_value)
+                    }
+                >
                     {display}
                 </option>
             }
         });
 
-        let view = view! {
-            <div>
-                <label for=&control.data.name class="form_label">
-                    {control.data.label.as_ref()}
-                </label>
-                <span class="form_error">{move || validation_state.get().take_msg()}</span>
-            </div>
+        let other_option_view = control.data.other_option.as_ref().map(|display| {
+            view! {
+                <option value=OTHER_OPTION_VALUE selected=other_active>
+                    {display}
+                </option>
+            }
+        });
+        let other_input_view = self.other_input(
+            &control.data.name,
+            other_active,
+            value_getter,
+            value_setter,
+            validation_state,
+            touched,
+        );
+
+        let select = view! {
             <select
                 id=&control.data.name
-                name=&control.data.name
+                tabindex=control.tabindex
+                size=control.data.size
+                name={
+                    // only the text input's value is sent while "other" is active
+                    let name = control.data.name.clone();
+                    move || (!other_active.get()).then(|| name.clone())
+                }
                 class="form_input"
                 class=("form_input_invalid", move || validation_state.get().is_err())
+                class=(
+                    "form_input_valid",
+                    move || touched.get() && validation_state.get().is_passed(),
+                )
                 on:input=move |ev| {
-                    value_setter.set(event_target_value(&ev));
+                    let value = event_target_value(&ev);
+                    if value == OTHER_OPTION_VALUE {
+                        other_chosen.set(true);
+                        value_setter.set(String::new());
+                    } else {
+                        other_chosen.set(false);
+                        value_setter.set(value);
+                    }
                 }
             >
                 {blank_option_view}
+                {status_option_view}
                 {options_view}
+                {other_option_view}
             </select>
-        }
-        .into_view();
+        };
+        let select = Self::with_native_validation(select, &control.native_validation);
+        let select = Self::bind_node_ref(select, control.node_ref);
+
+        let search_view = control.data.search.map(|search| {
+            view! {
+                <input
+                    type="search"
+                    class="form_input form_select_search"
+                    placeholder="Search…"
+                    prop:value=move || search.get()
+                    on:input=move |ev| search.set(event_target_value(&ev))
+                />
+            }
+        });
 
-        self.common_component(&control.styles, "select_parent", view)
+        let view = match search_view {
+            // the search input would be labeled instead of the select
+            Some(search_view) => view! {
+                {self.control_header(&control.data.name, control.data.label.as_ref(), validation_state, control.inline_errors)}
+                {search_view}
+                {select}
+                {other_input_view}
+            }
+            .into_view(),
+            None => self.labeled_control(
+                &control.data.name,
+                control.data.label.as_ref(),
+                validation_state,
+                control.inline_errors,
+                view! { {select} {other_input_view} }.into_view(),
+            ),
+        };
+
+        self.validated_component(
+            &control.styles,
+            &control.conditional_styles,
+            validation_state,
+            "select_parent",
+            view,
+        )
     }
 
     fn checkbox(
@@ -372,37 +1407,69 @@ impl FormStyle for GridFormStyle {
         control: Rc<ControlRenderData<Self, CheckboxData>>,
         value_getter: Signal<bool>,
         value_setter: SignalSetter<bool>,
+        validation_state: Signal<ValidationState>,
+        _touched: Signal<bool>,
     ) -> View {
         let label = control
             .data
             .label
             .clone()
-            .unwrap_or(control.data.name.clone());
+            .unwrap_or(MaybeSignal::Static(control.data.name.clone()));
+
+        // a hidden input with the same name sends the unchecked value,
+        // it is disabled when the checkbox is checked so only one is sent
+        let unchecked_view = control.data.unchecked_value.as_ref().map(|unchecked| {
+            view! {
+                <input
+                    type="hidden"
+                    name=&control.data.name
+                    value=unchecked
+                    prop:disabled=value_getter
+                />
+            }
+        });
+
+        let input = view! {
+            <input
+                type="checkbox"
+                id=&control.data.name
+                tabindex=control.tabindex
+                name=&control.data.name
+                value=control.data.checked_value.as_ref()
+                style="margin: auto 0;"
+                prop:checked=value_getter
+                on:input=move |ev| {
+                    let new_value = event_target_checked(&ev);
+                    value_setter.set(new_value);
+                }
+            />
+        };
+        let input = Self::with_native_validation(input, &control.native_validation);
+        let input = Self::bind_node_ref(input, control.node_ref);
 
         let view = view! {
+            {unchecked_view}
             <label
                 for=&control.data.name
                 class="form_checkbox"
                 class=("form_checkbox_checked", move || value_getter.get())
                 class=("form_checkbox_unchecked", move || !value_getter.get())
+                class=("form_checkbox_invalid", move || validation_state.get().is_err())
             >
-                <input
-                    type="checkbox"
-                    id=&control.data.name
-                    name=&control.data.name
-                    style="margin: auto 0;"
-                    prop:checked=value_getter
-                    on:input=move |ev| {
-                        let new_value = event_target_checked(&ev);
-                        value_setter.set(new_value);
-                    }
-                />
+                {input}
                 <span style="margin: auto 0.5rem;">{label}</span>
             </label>
+            {self.control_header(&control.data.name, None, validation_state, control.inline_errors)}
         }
         .into_view();
 
-        self.common_component(&control.styles, "checkbox_parent", view)
+        self.validated_component(
+            &control.styles,
+            &control.conditional_styles,
+            validation_state,
+            "checkbox_parent",
+            view,
+        )
     }
 
     fn stepper(
@@ -411,32 +1478,62 @@ impl FormStyle for GridFormStyle {
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View {
-        let view = view! {
-            <div>
-                <label for=&control.data.name class="form_label">
-                    {control.data.label.as_ref()}
-                </label>
-                <span class="form_error">{move || validation_state.get().take_msg()}</span>
-            </div>
+        let numeric_only = control.data.numeric_only;
+        let input = view! {
             <input
                 type="number"
                 id=&control.data.name
-                name=&control.data.name
+                tabindex=control.tabindex
+                name=control.data.submitted_value.is_none().then(|| control.data.name.clone())
+                readonly=control.data.readonly
                 step=control.data.step.clone()
                 min=control.data.min.clone()
                 max=control.data.max.clone()
                 class="form_input"
                 class=("form_input_invalid", move || validation_state.get().is_err())
+                class=(
+                    "form_input_valid",
+                    move || touched.get() && validation_state.get().is_passed(),
+                )
                 prop:value=move || value_getter.get()
+                on:beforeinput=move |ev| {
+                    if !numeric_only {
+                        return;
+                    }
+                    if let Some(text) = ev.data() {
+                        if !StepperData::is_numeric_input(&event_target_value(&ev), &text) {
+                            ev.prevent_default();
+                        }
+                    }
+                }
                 on:input=move |ev| {
                     value_setter.set(event_target_value(&ev));
                 }
             />
-        }
-        .into_view();
+        };
+        let input = Self::with_native_validation(input, &control.native_validation);
+        let input = Self::bind_node_ref(input, control.node_ref);
+        let input = Self::with_unit(input, &control.data.unit);
+        let input =
+            Self::with_submitted_value(input, &control.data.name, control.data.submitted_value);
+
+        let view = self.labeled_control(
+            &control.data.name,
+            control.data.label.as_ref(),
+            validation_state,
+            control.inline_errors,
+            input,
+        );
 
-        self.common_component(&control.styles, "stepper_parent", view)
+        self.validated_component(
+            &control.styles,
+            &control.conditional_styles,
+            validation_state,
+            "stepper_parent",
+            view,
+        )
     }
 
     fn slider(
@@ -445,31 +1542,49 @@ impl FormStyle for GridFormStyle {
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View {
-        let view = view! {
-            <div>
-                <label for=&control.data.name class="form_label">
-                    {control.data.label.as_ref()}
-                </label>
-                <span class="form_error">{move || validation_state.get().take_msg()}</span>
-            </div>
+        let input = view! {
             <input
                 type="range"
                 id=&control.data.name
-                name=&control.data.name
+                tabindex=control.tabindex
+                name=control.data.submitted_value.is_none().then(|| control.data.name.clone())
                 min=control.data.min.clone()
                 max=control.data.max.clone()
                 class="form_input"
                 class=("form_input_invalid", move || validation_state.get().is_err())
+                class=(
+                    "form_input_valid",
+                    move || touched.get() && validation_state.get().is_passed(),
+                )
                 prop:value=move || value_getter.get()
                 on:input=move |ev| {
                     let value = event_target_value(&ev);
                     value_setter.set(value);
                 }
             />
-        }
-        .into_view();
+        };
+        let input = Self::with_native_validation(input, &control.native_validation);
+        let input = Self::bind_node_ref(input, control.node_ref);
+        let input = Self::with_unit(input, &control.data.unit);
+        let input =
+            Self::with_submitted_value(input, &control.data.name, control.data.submitted_value);
+
+        let view = self.labeled_control(
+            &control.data.name,
+            control.data.label.as_ref(),
+            validation_state,
+            control.inline_errors,
+            input,
+        );
 
-        self.common_component(&control.styles, "slider_parent", view)
+        self.validated_component(
+            &control.styles,
+            &control.conditional_styles,
+            validation_state,
+            "slider_parent",
+            view,
+        )
     }
 }
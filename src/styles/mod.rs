@@ -1,21 +1,46 @@
 mod grid_form;
+mod reactive_style;
 
 use crate::controls::{
-    button::ButtonData, checkbox::CheckboxData, heading::HeadingData, hidden::HiddenData,
-    output::OutputData, radio_buttons::RadioButtonsData, select::SelectData, slider::SliderData,
-    spacer::SpacerData, stepper::StepperData, submit::SubmitData, text_area::TextAreaData,
-    text_input::TextInputData, ControlRenderData, ValidationState,
+    button::ButtonData,
+    change_summary::{ChangeSummaryData, FieldChange},
+    checkbox::CheckboxData,
+    display::DisplayData,
+    group::{AccordionSectionData, TitledGroupData},
+    heading::HeadingData,
+    hidden::HiddenData,
+    output::OutputData,
+    progress::ProgressData,
+    radio_buttons::RadioButtonsData,
+    select::SelectData,
+    slider::SliderData,
+    spacer::SpacerData,
+    stepper::StepperData,
+    submit::SubmitData,
+    text_area::TextAreaData,
+    text_input::TextInputData,
+    ControlRenderData, ValidationState,
 };
 use leptos::{Signal, SignalSetter, View};
 use std::rc::Rc;
 
 pub use grid_form::{GFStyleAttr, GridFormStyle};
+pub use reactive_style::ReactiveStyle;
 
 /// Defines a way to style a form.
 ///
 /// Provides methods for rendering all the controls.
 /// This provider is in charge of figuring out what html elements should be
 /// rendered and how they should be styled.
+///
+/// Controls that can be validated are given a `touched` signal, which
+/// becomes true once the user has changed the value of the control or the
+/// control has lost focus. This can be used to only show a success state
+/// after the user has changed the value.
+///
+/// With SSR, these methods also run on the server. Any browser-only APIs
+/// (like `web_sys::window()`) should only be used in event handlers or
+/// effects, which only run once the view is mounted in the browser.
 pub trait FormStyle: 'static {
     /// The type of styling attributes that this [`FormStyle`] takes.
     ///
@@ -29,7 +54,19 @@ pub trait FormStyle: 'static {
     ///
     /// Do NOT wrap it in an actual `form` element; any
     /// wrapping should be done with `div` or similar elements.
-    fn form_frame(&self, form: ControlRenderData<Self, View>) -> View;
+    ///
+    /// `is_valid` is false while any of the form's controls has a parse or
+    /// validation error, which allows styling the whole form differently.
+    fn form_frame(&self, form: ControlRenderData<Self, View>, is_valid: Signal<bool>) -> View;
+
+    /// Renders a summary of the form's error messages.
+    ///
+    /// This is only used when the form's
+    /// [`ErrorDisplay`](crate::ErrorDisplay) shows a summary. `errors` is
+    /// empty until submitting the form fails, and then has the error
+    /// messages of the invalid controls. Nothing should be shown while it
+    /// is empty.
+    fn error_summary(&self, errors: Signal<Vec<String>>) -> View;
 
     /// Wraps the view of a custom component.
     ///
@@ -42,6 +79,13 @@ pub trait FormStyle: 'static {
     /// aforementioned reasons.
     fn custom_component(&self, style: &[Self::StylingAttributes], inner: View) -> View;
 
+    /// Adds expandable help beneath a control.
+    ///
+    /// `control` is the rendered control. The `summary` should always be
+    /// shown, and the `content` only once the user expands it, like with
+    /// the html `<details>` element. It should be collapsed by default.
+    fn details_help(&self, control: View, summary: String, content: String) -> View;
+
     /// Renders a group.
     ///
     /// The inner view for the group's components is provided.
@@ -49,6 +93,37 @@ pub trait FormStyle: 'static {
     /// and apply the styles.
     fn group(&self, group: Rc<ControlRenderData<Self, View>>) -> View;
 
+    /// Renders a group with a title.
+    ///
+    /// Like [`group`](Self::group), but this should also show the title
+    /// and the number of controls in the group with errors.
+    ///
+    /// See [`TitledGroupData`].
+    fn titled_group(&self, group: Rc<ControlRenderData<Self, TitledGroupData>>) -> View;
+
+    /// Renders a section of an accordion.
+    ///
+    /// Like [`titled_group`](Self::titled_group), but the title should
+    /// toggle whether the section is open, and the inner view should be
+    /// collapsed while it is closed. The inner view must stay in the DOM
+    /// while collapsed, so that its inputs are still sent with the form.
+    ///
+    /// See [`AccordionSectionData`].
+    fn accordion_section(&self, section: Rc<ControlRenderData<Self, AccordionSectionData>>)
+        -> View;
+
+    /// Renders the value of a control as read only text.
+    ///
+    /// This is used in place of the interactive controls when the form is
+    /// in [`FormMode::Display`](crate::FormMode::Display).
+    ///
+    /// See [`DisplayData`].
+    fn display(
+        &self,
+        control: Rc<ControlRenderData<Self, DisplayData>>,
+        value_getter: Signal<String>,
+    ) -> View;
+
     /// Renders a spacer.
     ///
     /// See [`SpacerData`].
@@ -63,11 +138,14 @@ pub trait FormStyle: 'static {
 
     /// Renders a submit button.
     ///
+    /// `pending` is whether the form is being submitted.
+    ///
     /// See [`SubmitData`].
     fn submit(
         &self,
         control: Rc<ControlRenderData<Self, SubmitData>>,
         value_getter: Option<Signal<String>>,
+        pending: Signal<bool>,
     ) -> View;
 
     /// Renders a button.
@@ -88,6 +166,25 @@ pub trait FormStyle: 'static {
         value_getter: Option<Signal<String>>,
     ) -> View;
 
+    /// Renders a bar showing how complete the form is.
+    ///
+    /// `completion` goes from 0.0 to 1.0. See [`ProgressData`].
+    fn progress(
+        &self,
+        control: Rc<ControlRenderData<Self, ProgressData>>,
+        completion: Signal<f32>,
+    ) -> View;
+
+    /// Renders a list of the fields that have changed from their initial
+    /// values, with their old and new values.
+    ///
+    /// See [`ChangeSummaryData`].
+    fn change_summary(
+        &self,
+        control: Rc<ControlRenderData<Self, ChangeSummaryData>>,
+        changes: Signal<Vec<FieldChange>>,
+    ) -> View;
+
     /// Renders a input control that should be hidden from the user.
     ///
     /// See [`HiddenData`].
@@ -106,6 +203,7 @@ pub trait FormStyle: 'static {
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View;
 
     /// Renders a text area control.
@@ -117,6 +215,7 @@ pub trait FormStyle: 'static {
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View;
 
     /// Renders a group of radio buttons.
@@ -128,6 +227,7 @@ pub trait FormStyle: 'static {
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View;
 
     /// Renders a select (or dropdown) control.
@@ -139,6 +239,7 @@ pub trait FormStyle: 'static {
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View;
 
     /// Renders a checkbox control.
@@ -149,6 +250,8 @@ pub trait FormStyle: 'static {
         control: Rc<ControlRenderData<Self, CheckboxData>>,
         value_getter: Signal<bool>,
         value_setter: SignalSetter<bool>,
+        validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View;
 
     /// Renders a stepper control.
@@ -160,6 +263,7 @@ pub trait FormStyle: 'static {
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View;
 
     /// Renders a slider control.
@@ -171,5 +275,6 @@ pub trait FormStyle: 'static {
         value_getter: Signal<String>,
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
     ) -> View;
 }
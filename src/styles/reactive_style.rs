@@ -0,0 +1,333 @@
+use super::FormStyle;
+use crate::controls::{
+    button::ButtonData,
+    change_summary::{ChangeSummaryData, FieldChange},
+    checkbox::CheckboxData,
+    display::DisplayData,
+    group::{AccordionSectionData, TitledGroupData},
+    heading::HeadingData,
+    hidden::HiddenData,
+    output::OutputData,
+    progress::ProgressData,
+    radio_buttons::RadioButtonsData,
+    select::SelectData,
+    slider::SliderData,
+    spacer::SpacerData,
+    stepper::StepperData,
+    submit::SubmitData,
+    text_area::TextAreaData,
+    text_input::TextInputData,
+    ControlRenderData, ValidationState,
+};
+use leptos::{IntoView, Signal, SignalSetter, SignalWith, View};
+use std::rc::Rc;
+
+/// A [`FormStyle`] that renders with the current value of a signal of
+/// another style.
+///
+/// When the signal changes, the controls are re-rendered with the new
+/// style. The form data, validation state, and other state of the controls
+/// are kept, since they are not owned by the style. This is useful for
+/// changing the theme of a form at runtime, without rebuilding it.
+///
+/// To use it, set the `Style` of your form data to
+/// `ReactiveStyle<YourStyle>`. The styling attributes are the same as the
+/// inner style's.
+///
+/// This only switches between values of one style type, like two
+/// configurations of the [`GridFormStyle`](struct@super::GridFormStyle).
+/// To switch between different style types, wrap them in your own enum
+/// that implements [`FormStyle`] by forwarding to the selected style, and
+/// use that as the inner style.
+#[derive(Clone, Copy)]
+pub struct ReactiveStyle<FS: FormStyle> {
+    style: Signal<FS>,
+}
+
+impl<FS: FormStyle> ReactiveStyle<FS> {
+    /// Creates a new [`ReactiveStyle`] that renders with the current value
+    /// of `style`.
+    pub fn new(style: impl Into<Signal<FS>>) -> Self {
+        ReactiveStyle {
+            style: style.into(),
+        }
+    }
+
+    /// Converts the render data of a control to the render data of the
+    /// inner style.
+    fn inner<C: Clone>(control: &ControlRenderData<Self, C>) -> ControlRenderData<FS, C> {
+        ControlRenderData {
+            styles: control.styles.clone(),
+            conditional_styles: control.conditional_styles.clone(),
+            node_ref: control.node_ref,
+            tabindex: control.tabindex,
+            inline_errors: control.inline_errors,
+            native_validation: control.native_validation.clone(),
+            data: control.data.clone(),
+        }
+    }
+
+    /// Renders the view with the current style, re-rendering it when the
+    /// style changes.
+    fn render(&self, render: impl Fn(&FS) -> View + 'static) -> View {
+        let style = self.style;
+        (move || style.with(|fs| render(fs))).into_view()
+    }
+}
+
+impl<FS: FormStyle> FormStyle for ReactiveStyle<FS> {
+    type StylingAttributes = FS::StylingAttributes;
+
+    fn form_frame(&self, form: ControlRenderData<Self, View>, is_valid: Signal<bool>) -> View {
+        self.render(move |fs| fs.form_frame(Self::inner(&form), is_valid))
+    }
+
+    fn error_summary(&self, errors: Signal<Vec<String>>) -> View {
+        self.render(move |fs| fs.error_summary(errors))
+    }
+
+    fn custom_component(&self, style: &[Self::StylingAttributes], inner: View) -> View {
+        let style = style.to_vec();
+        self.render(move |fs| fs.custom_component(&style, inner.clone()))
+    }
+
+    fn details_help(&self, control: View, summary: String, content: String) -> View {
+        self.render(move |fs| fs.details_help(control.clone(), summary.clone(), content.clone()))
+    }
+
+    fn group(&self, group: Rc<ControlRenderData<Self, View>>) -> View {
+        let group = Rc::new(Self::inner(&group));
+        self.render(move |fs| fs.group(group.clone()))
+    }
+
+    fn titled_group(&self, group: Rc<ControlRenderData<Self, TitledGroupData>>) -> View {
+        let group = Rc::new(Self::inner(&group));
+        self.render(move |fs| fs.titled_group(group.clone()))
+    }
+
+    fn accordion_section(
+        &self,
+        section: Rc<ControlRenderData<Self, AccordionSectionData>>,
+    ) -> View {
+        let section = Rc::new(Self::inner(&section));
+        self.render(move |fs| fs.accordion_section(section.clone()))
+    }
+
+    fn display(
+        &self,
+        control: Rc<ControlRenderData<Self, DisplayData>>,
+        value_getter: Signal<String>,
+    ) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| fs.display(control.clone(), value_getter))
+    }
+
+    fn spacer(&self, control: Rc<ControlRenderData<Self, SpacerData>>) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| fs.spacer(control.clone()))
+    }
+
+    fn heading(
+        &self,
+        control: Rc<ControlRenderData<Self, HeadingData>>,
+        value_getter: Option<Signal<String>>,
+    ) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| fs.heading(control.clone(), value_getter))
+    }
+
+    fn submit(
+        &self,
+        control: Rc<ControlRenderData<Self, SubmitData>>,
+        value_getter: Option<Signal<String>>,
+        pending: Signal<bool>,
+    ) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| fs.submit(control.clone(), value_getter, pending))
+    }
+
+    fn button(
+        &self,
+        control: Rc<ControlRenderData<Self, ButtonData>>,
+        value_getter: Option<Signal<String>>,
+    ) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| fs.button(control.clone(), value_getter))
+    }
+
+    fn output(
+        &self,
+        control: Rc<ControlRenderData<Self, OutputData>>,
+        value_getter: Option<Signal<String>>,
+    ) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| fs.output(control.clone(), value_getter))
+    }
+
+    fn progress(
+        &self,
+        control: Rc<ControlRenderData<Self, ProgressData>>,
+        completion: Signal<f32>,
+    ) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| fs.progress(control.clone(), completion))
+    }
+
+    fn change_summary(
+        &self,
+        control: Rc<ControlRenderData<Self, ChangeSummaryData>>,
+        changes: Signal<Vec<FieldChange>>,
+    ) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| fs.change_summary(control.clone(), changes))
+    }
+
+    fn hidden(
+        &self,
+        control: Rc<ControlRenderData<Self, HiddenData>>,
+        value_getter: Option<Signal<String>>,
+    ) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| fs.hidden(control.clone(), value_getter))
+    }
+
+    fn text_input(
+        &self,
+        control: Rc<ControlRenderData<Self, TextInputData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
+    ) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| {
+            fs.text_input(
+                control.clone(),
+                value_getter,
+                value_setter,
+                validation_state,
+                touched,
+            )
+        })
+    }
+
+    fn text_area(
+        &self,
+        control: Rc<ControlRenderData<Self, TextAreaData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
+    ) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| {
+            fs.text_area(
+                control.clone(),
+                value_getter,
+                value_setter,
+                validation_state,
+                touched,
+            )
+        })
+    }
+
+    fn radio_buttons(
+        &self,
+        control: Rc<ControlRenderData<Self, RadioButtonsData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
+    ) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| {
+            fs.radio_buttons(
+                control.clone(),
+                value_getter,
+                value_setter,
+                validation_state,
+                touched,
+            )
+        })
+    }
+
+    fn select(
+        &self,
+        control: Rc<ControlRenderData<Self, SelectData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
+    ) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| {
+            fs.select(
+                control.clone(),
+                value_getter,
+                value_setter,
+                validation_state,
+                touched,
+            )
+        })
+    }
+
+    fn checkbox(
+        &self,
+        control: Rc<ControlRenderData<Self, CheckboxData>>,
+        value_getter: Signal<bool>,
+        value_setter: SignalSetter<bool>,
+        validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
+    ) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| {
+            fs.checkbox(
+                control.clone(),
+                value_getter,
+                value_setter,
+                validation_state,
+                touched,
+            )
+        })
+    }
+
+    fn stepper(
+        &self,
+        control: Rc<ControlRenderData<Self, StepperData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
+    ) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| {
+            fs.stepper(
+                control.clone(),
+                value_getter,
+                value_setter,
+                validation_state,
+                touched,
+            )
+        })
+    }
+
+    fn slider(
+        &self,
+        control: Rc<ControlRenderData<Self, SliderData>>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+        touched: Signal<bool>,
+    ) -> View {
+        let control = Rc::new(Self::inner(&control));
+        self.render(move |fs| {
+            fs.slider(
+                control.clone(),
+                value_getter,
+                value_setter,
+                validation_state,
+                touched,
+            )
+        })
+    }
+}
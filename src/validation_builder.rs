@@ -7,6 +7,36 @@ use std::fmt::Display;
 /// but takes a &str for the name of the field for improved error messages.
 type ValidationBuilderFn<T> = dyn Fn(&str, &T) -> Result<(), String> + 'static;
 
+/// The native html validation attributes that mirror the rules of a
+/// [`ValidationBuilder`].
+///
+/// These let the browser enforce the basic rules of a form when javascript
+/// is not available. See
+/// [`native_validation_fallback`](crate::FormBuilder::native_validation_fallback).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct NativeValidation {
+    /// Whether the `required` attribute should be set.
+    pub required: bool,
+    /// The value of the `minlength` attribute.
+    ///
+    /// The browser counts the length in UTF-16 code units, while the
+    /// validation counts characters, so characters like emoji count as two
+    /// for the browser.
+    pub min_len: Option<usize>,
+    /// The value of the `maxlength` attribute.
+    ///
+    /// The browser counts the length in UTF-16 code units, while the
+    /// validation counts characters, so characters like emoji count as two
+    /// for the browser.
+    pub max_len: Option<usize>,
+    /// The value of the `min` attribute.
+    pub min: Option<String>,
+    /// The value of the `max` attribute.
+    pub max: Option<String>,
+    /// The value of the `pattern` attribute.
+    pub pattern: Option<String>,
+}
+
 /// A helper builder that allows you to specify a validation function
 /// declaritivly
 ///
@@ -21,6 +51,8 @@ pub struct ValidationBuilder<FD: FormToolData, T: ?Sized + 'static> {
     field_fn: Box<dyn Fn(&FD) -> &T + 'static>,
     /// The functions to be called when validating.
     functions: Vec<Box<ValidationBuilderFn<T>>>,
+    /// The native html validation attributes for the rules that have them.
+    native: NativeValidation,
 }
 
 impl<FD: FormToolData, T: ?Sized + 'static> ValidationBuilder<FD, T> {
@@ -30,6 +62,7 @@ impl<FD: FormToolData, T: ?Sized + 'static> ValidationBuilder<FD, T> {
             name: String::from("Field"),
             field_fn: Box::new(field_fn),
             functions: Vec::new(),
+            native: NativeValidation::default(),
         }
     }
 
@@ -50,6 +83,24 @@ impl<FD: FormToolData, T: ?Sized + 'static> ValidationBuilder<FD, T> {
         self
     }
 
+    /// Sets the `pattern` attribute used by the native validation fallback.
+    ///
+    /// This only affects the native html validation, so it should mirror a
+    /// rule that is also checked by this builder, like one added with
+    /// [`custom`](Self::custom).
+    pub fn native_pattern(mut self, pattern: impl ToString) -> Self {
+        self.native.pattern = Some(pattern.to_string());
+        self
+    }
+
+    /// Gets the native html validation attributes that mirror the rules of
+    /// this builder.
+    ///
+    /// Rules without an html equivalent, like custom rules, are left out.
+    pub fn native_validation(&self) -> NativeValidation {
+        self.native.clone()
+    }
+
     /// Builds the action validation function.
     pub fn build(self) -> impl ValidationFn<FD> {
         move |form_data| {
@@ -68,6 +119,7 @@ impl<FD: FormToolData, T: ?Sized + 'static> ValidationBuilder<FD, T> {
 impl<FD: FormToolData, T> ValidationBuilder<FD, Option<T>> {
     /// Requires the field to be `Some`.
     pub fn required(mut self) -> Self {
+        self.native.required = true;
         self.functions.push(Box::new(move |name, value| {
             if value.is_none() {
                 Err(format!("{} is required", name))
@@ -79,9 +131,32 @@ impl<FD: FormToolData, T> ValidationBuilder<FD, Option<T>> {
     }
 }
 
+impl<FD: FormToolData> ValidationBuilder<FD, bool> {
+    /// Requires the field to be `true`, like a checkbox that must be
+    /// checked.
+    ///
+    /// `msg` is used as the error message, like "You must agree to the
+    /// terms".
+    pub fn must_be_true(mut self, msg: impl ToString) -> Self {
+        self.native.required = true;
+        let msg = msg.to_string();
+        self.functions.push(Box::new(
+            move |_name, value| {
+                if !*value {
+                    Err(msg.clone())
+                } else {
+                    Ok(())
+                }
+            },
+        ));
+        self
+    }
+}
+
 impl<FD: FormToolData> ValidationBuilder<FD, str> {
     /// Requires the field to not be empty.
     pub fn required(mut self) -> Self {
+        self.native.required = true;
         self.functions.push(Box::new(move |name, value| {
             if value.is_empty() {
                 Err(format!("{} is required", name))
@@ -92,10 +167,29 @@ impl<FD: FormToolData> ValidationBuilder<FD, str> {
         self
     }
 
+    /// Requires the field to not be empty or only whitespace.
+    ///
+    /// Unlike [`required`](Self::required), a value of only spaces fails
+    /// this validation.
+    pub fn required_non_blank(mut self) -> Self {
+        self.native.required = true;
+        self.functions.push(Box::new(move |name, value| {
+            if value.trim().is_empty() {
+                Err(format!("{} is required", name))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
     /// Requires the field's length to be at least `min_len`.
+    ///
+    /// The length is counted in characters.
     pub fn min_len(mut self, min_len: usize) -> Self {
+        self.native.min_len = Some(min_len);
         self.functions.push(Box::new(move |name, value| {
-            if value.len() < min_len {
+            if value.chars().count() < min_len {
                 Err(format!("{} must be >= {} characters", name, min_len))
             } else {
                 Ok(())
@@ -105,9 +199,12 @@ impl<FD: FormToolData> ValidationBuilder<FD, str> {
     }
 
     /// Requires the field's length to be less than or equal to `min_len`.
+    ///
+    /// The length is counted in characters.
     pub fn max_len(mut self, max_len: usize) -> Self {
+        self.native.max_len = Some(max_len);
         self.functions.push(Box::new(move |name, value| {
-            if value.len() > max_len {
+            if value.chars().count() > max_len {
                 Err(format!("{} must be <= {} characters", name, max_len))
             } else {
                 Ok(())
@@ -116,6 +213,44 @@ impl<FD: FormToolData> ValidationBuilder<FD, str> {
         self
     }
 
+    /// Requires the field to be in the provided whitelist, ignoring case.
+    ///
+    /// Case is compared after converting both strings to lowercase with
+    /// [`str::to_lowercase`], so this is unicode-aware.
+    pub fn whitelist_ci(mut self, whitelist: Vec<impl ToString>) -> Self {
+        let whitelist: Vec<String> = whitelist
+            .into_iter()
+            .map(|v| v.to_string().to_lowercase())
+            .collect();
+        self.functions.push(Box::new(move |name, value| {
+            if !whitelist.contains(&value.to_lowercase()) {
+                Err(format!("{} cannot be {}", name, value))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the field to not be in the provided blacklist, ignoring case.
+    ///
+    /// Case is compared after converting both strings to lowercase with
+    /// [`str::to_lowercase`], so this is unicode-aware.
+    pub fn blacklist_ci(mut self, blacklist: Vec<impl ToString>) -> Self {
+        let blacklist: Vec<String> = blacklist
+            .into_iter()
+            .map(|v| v.to_string().to_lowercase())
+            .collect();
+        self.functions.push(Box::new(move |name, value| {
+            if blacklist.contains(&value.to_lowercase()) {
+                Err(format!("{} cannot be {}", name, value))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
     /// Requires the field to contain `pattern`.
     pub fn contains(mut self, pattern: impl ToString) -> Self {
         let pattern = pattern.to_string();
@@ -134,6 +269,7 @@ impl<FD: FormToolData, T: PartialOrd<T> + Display + 'static> ValidationBuilder<F
     /// Requires the value to be at least `min_value` according to
     /// `PartialOrd`.
     pub fn min_value(mut self, min_value: T) -> Self {
+        self.native.min = Some(min_value.to_string());
         self.functions.push(Box::new(move |name, value| {
             if value < &min_value {
                 Err(format!("{} mut be >= {}", name, min_value))
@@ -147,6 +283,7 @@ impl<FD: FormToolData, T: PartialOrd<T> + Display + 'static> ValidationBuilder<F
     /// Requires the value to be at most `max_value` according to
     /// `PartialOrd`.
     pub fn max_value(mut self, max_value: T) -> Self {
+        self.native.max = Some(max_value.to_string());
         self.functions.push(Box::new(move |name, value| {
             if value > &max_value {
                 Err(format!("{} mut be <= {}", name, max_value))
@@ -156,6 +293,27 @@ impl<FD: FormToolData, T: PartialOrd<T> + Display + 'static> ValidationBuilder<F
         }));
         self
     }
+
+    /// Requires the value to be between `min_value` and `max_value`
+    /// (inclusive) according to `PartialOrd`.
+    ///
+    /// This is like using both [`min_value`](Self::min_value) and
+    /// [`max_value`](Self::max_value), but with a single error message.
+    pub fn range(mut self, min_value: T, max_value: T) -> Self {
+        self.native.min = Some(min_value.to_string());
+        self.native.max = Some(max_value.to_string());
+        self.functions.push(Box::new(move |name, value| {
+            if value < &min_value || value > &max_value {
+                Err(format!(
+                    "{} must be between {} and {}",
+                    name, min_value, max_value
+                ))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
 }
 
 impl<FD: FormToolData, T: PartialEq<T> + Display + 'static> ValidationBuilder<FD, T> {